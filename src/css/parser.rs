@@ -34,12 +34,12 @@ impl CssParser {
         self.next_pos -= 1;
     }
 
-    fn create_color_from_i32(&self, r: i32, g: i32, b: i32) -> Value {
+    fn create_color_from_i32(&self, r: i32, g: i32, b: i32, a: f32) -> Value {
         if !(0..=255).contains(&r) || !(0..=255).contains(&g) || !(0..=255).contains(&b) {
             panic!("Invalid color values");
         }
 
-        Value::Rgb(Rgb::new(r as u8, g as u8, b as u8, 1.))
+        Value::Rgb(Rgb::new(r as u8, g as u8, b as u8, a.clamp(0., 1.)))
     }
 
     fn consume_if_starts(&mut self, s: &str) -> bool {
@@ -145,17 +145,44 @@ impl CssParser {
         selectors
     }
 
-    fn consume_number(&mut self) -> i32 {
+    fn consume_number(&mut self) -> Option<f32> {
         self.consume_until(|c| !c.is_whitespace());
-        self.consume_until_and_return(|c| !c.is_numeric())
+
+        let sign = if self.consume_if_starts("-") { -1. } else { 1. };
+
+        let number: f32 = self
+            .consume_until_and_return(|c| !c.is_numeric() && c != '.')
             .parse()
-            .unwrap()
+            .ok()?;
+
+        Some(sign * number)
+    }
+
+    fn starts_number(&self) -> bool {
+        match self.next_code_point() {
+            Some('-') => matches!(self.peek(1), Some(c) if c.is_ascii_digit() || c == '.'),
+            Some(c) => c.is_ascii_digit() || c == '.',
+            None => false,
+        }
     }
 
     fn consume_values(&mut self) -> Option<Vec<Value>> {
+        self.consume_values_until(|c| matches!(c, Some(';') | Some('}') | None))
+    }
+
+    /// Consumes values until `is_end` matches the next code point. Values that cannot be parsed
+    /// are still consumed, but make the whole list invalid.
+    fn consume_values_until(&mut self, is_end: fn(Option<char>) -> bool) -> Option<Vec<Value>> {
         let mut ans = Vec::new();
+        let mut is_valid = true;
 
         loop {
+            self.consume_until(|c| !c.is_whitespace());
+
+            if is_end(self.next_code_point()) {
+                break;
+            }
+
             if self.next_code_point() == Some(':') {
                 panic!("Unexpected ':'");
             }
@@ -168,41 +195,102 @@ impl CssParser {
                 let b = self.consume_number();
                 self.consume_next_code_point();
 
-                ans.push(self.create_color_from_i32(r, g, b));
-            } else if matches!(self.next_code_point(), Some(c) if c.is_ascii_digit()) {
-                let number = self.consume_number();
+                match (r, g, b) {
+                    (Some(r), Some(g), Some(b)) => {
+                        ans.push(self.create_color_from_i32(r as i32, g as i32, b as i32, 1.))
+                    }
+                    _ => is_valid = false,
+                }
+            } else if self.consume_if_starts("rgba(") {
+                let r = self.consume_number();
+                self.consume_next_code_point();
+                let g = self.consume_number();
+                self.consume_next_code_point();
+                let b = self.consume_number();
+                self.consume_next_code_point();
+                let a = self.consume_number();
+                self.consume_next_code_point();
+
+                match (r, g, b, a) {
+                    (Some(r), Some(g), Some(b), Some(a)) => {
+                        ans.push(self.create_color_from_i32(r as i32, g as i32, b as i32, a))
+                    }
+                    _ => is_valid = false,
+                }
+            } else if self.consume_if_starts("url(") {
+                self.consume_until(|c| !c.is_whitespace());
+
+                let url = match self.next_code_point() {
+                    Some(quote @ ('"' | '\'')) => {
+                        self.consume_next_code_point();
+                        let url = self.consume_until_and_return(|c| c == quote);
+                        self.consume_next_code_point();
+                        url
+                    }
+                    _ => self.consume_until_and_return(|c| c == ')' || c.is_whitespace()),
+                };
+
+                self.consume_until(|c| c == ')');
+                self.consume_next_code_point();
+
+                ans.push(Value::Url(url));
+            } else if self.starts_number() {
+                let Some(number) = self.consume_number() else {
+                    is_valid = false;
+                    continue;
+                };
 
                 match self.next_code_point() {
                     Some('%') => {
                         self.consume_next_code_point();
-                        ans.push(Value::Percentage(number as f32));
+                        ans.push(Value::Percentage(number));
                     }
                     _ => {
                         let unit = self.consume_identifier();
 
+                        // Angles are all converted to degrees.
                         match unit.as_str() {
-                            "px" => ans.push(Value::Dimension(number as f32, Unit::Px)),
-                            "" => ans.push(Value::Dimension(number as f32, Unit::None)),
-                            _ => return None,
+                            "px" => ans.push(Value::Dimension(number, Unit::Px)),
+                            "deg" => ans.push(Value::Dimension(number, Unit::Deg)),
+                            "grad" => ans.push(Value::Dimension(number * 0.9, Unit::Deg)),
+                            "rad" => ans.push(Value::Dimension(number.to_degrees(), Unit::Deg)),
+                            "turn" => ans.push(Value::Dimension(number * 360., Unit::Deg)),
+                            "" => ans.push(Value::Dimension(number, Unit::None)),
+                            _ => is_valid = false,
                         }
                     }
                 }
+            } else if self.next_code_point() == Some(',') {
+                self.consume_next_code_point();
+                ans.push(Value::Comma);
+            } else if self.next_code_point() == Some('/') {
+                self.consume_next_code_point();
+                ans.push(Value::Slash);
+            } else if let Some(quote @ ('"' | '\'')) = self.next_code_point() {
+                self.consume_next_code_point();
+                ans.push(Value::String(self.consume_until_and_return(|c| c == quote)));
+                self.consume_next_code_point();
             } else {
-                ans.push(Value::Keyword(
-                    self.consume_until_and_return(|c| !c.is_alphabetic()),
-                ))
-            }
-
-            self.consume_until(|c| !c.is_whitespace());
-
-            let next_code_point = self.next_code_point();
-
-            if matches!(next_code_point, Some(';') | Some('}') | None) {
-                break;
+                let identifier = self.consume_identifier();
+
+                if identifier.is_empty() {
+                    self.consume_next_code_point();
+                    is_valid = false;
+                } else if self.consume_if_starts("(") {
+                    let arguments = self.consume_values_until(|c| matches!(c, Some(')') | None));
+                    self.consume_next_code_point();
+
+                    match arguments {
+                        Some(arguments) => ans.push(Value::Function(identifier, arguments)),
+                        None => is_valid = false,
+                    }
+                } else {
+                    ans.push(Value::Keyword(identifier))
+                }
             }
         }
 
-        Some(ans)
+        is_valid.then_some(ans)
     }
 
     fn consume_declaration(&mut self) -> Option<Declaration> {
@@ -397,4 +485,127 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_parse_comma_separated_values() {
+        let mut parser = CssParser::new("font-family: \"Open Sans\", Times New Roman, sans-serif;");
+
+        assert_eq!(
+            parser.parse_list_of_declarations(),
+            vec![Declaration {
+                name: "font-family".to_string(),
+                value: vec![
+                    Value::String("Open Sans".to_string()),
+                    Value::Comma,
+                    Value::Keyword("Times".to_string()),
+                    Value::Keyword("New".to_string()),
+                    Value::Keyword("Roman".to_string()),
+                    Value::Comma,
+                    Value::Keyword("sans-serif".to_string()),
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_slash_and_fractional_values() {
+        let mut parser = CssParser::new("font: italic bold 14px/1.5 Georgia, serif");
+
+        assert_eq!(
+            parser.parse_list_of_declarations(),
+            vec![Declaration {
+                name: "font".to_string(),
+                value: vec![
+                    Value::Keyword("italic".to_string()),
+                    Value::Keyword("bold".to_string()),
+                    Value::Dimension(14.0, Unit::Px),
+                    Value::Slash,
+                    Value::Dimension(1.5, Unit::None),
+                    Value::Keyword("Georgia".to_string()),
+                    Value::Comma,
+                    Value::Keyword("serif".to_string()),
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_negative_values_and_rgba() {
+        let mut parser = CssParser::new("box-shadow: -2px -.5px 4px rgba(0, 0, 0, 0.25)");
+
+        assert_eq!(
+            parser.parse_list_of_declarations(),
+            vec![Declaration {
+                name: "box-shadow".to_string(),
+                value: vec![
+                    Value::Dimension(-2.0, Unit::Px),
+                    Value::Dimension(-0.5, Unit::Px),
+                    Value::Dimension(4.0, Unit::Px),
+                    Value::Rgb(Rgb::new(0, 0, 0, 0.25)),
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_urls() {
+        let mut parser =
+            CssParser::new("background: url(images/tile.png) no-repeat, url( \"a b.jpg\" )");
+
+        assert_eq!(
+            parser.parse_list_of_declarations(),
+            vec![Declaration {
+                name: "background".to_string(),
+                value: vec![
+                    Value::Url("images/tile.png".to_string()),
+                    Value::Keyword("no-repeat".to_string()),
+                    Value::Comma,
+                    Value::Url("a b.jpg".to_string()),
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_functions() {
+        let mut parser =
+            CssParser::new("background-image: linear-gradient(0.25turn, red, blue 80%)");
+
+        assert_eq!(
+            parser.parse_list_of_declarations(),
+            vec![Declaration {
+                name: "background-image".to_string(),
+                value: vec![Value::Function(
+                    "linear-gradient".to_string(),
+                    vec![
+                        Value::Dimension(90.0, Unit::Deg),
+                        Value::Comma,
+                        Value::Keyword("red".to_string()),
+                        Value::Comma,
+                        Value::Keyword("blue".to_string()),
+                        Value::Percentage(80.0),
+                    ],
+                )],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_drop_declarations_with_malformed_numbers() {
+        for input in ["width: 1.2.3px", "margin: .", "font-family: .Helvetica"] {
+            let mut parser = CssParser::new(input);
+
+            assert_eq!(parser.parse_list_of_declarations(), vec![]);
+        }
+
+        let mut parser = CssParser::new("margin: .; color: red");
+
+        assert_eq!(
+            parser.parse_list_of_declarations(),
+            vec![Declaration {
+                name: "color".to_string(),
+                value: vec![Value::Keyword("red".to_string())],
+            }]
+        );
+    }
 }
@@ -43,6 +43,12 @@ pub(crate) enum Value {
     Dimension(f32, Unit),
     Percentage(f32),
     Keyword(String),
+    String(String),
+    Url(String),
+    /// A function other than `rgb()`, `rgba()` and `url()`, with its arguments.
+    Function(String, Vec<Value>),
+    Comma,
+    Slash,
     NotDeclared,
     #[default]
     Temporal,
@@ -65,6 +71,7 @@ impl Rgb {
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum Unit {
     Px,
+    Deg,
     None,
 }
 
@@ -2,6 +2,7 @@ use std::path::Path;
 
 use crate::layout::layout_node::LayoutNode;
 use crate::layout::tree_builder::LayoutTreeBuilder;
+use crate::painter::fonts_context::FontsContext;
 use crate::style::types::StyledNode;
 
 pub(crate) mod box_dimensions;
@@ -14,10 +15,418 @@ pub fn build_layout_tree<'a>(
     style_tree: &'a StyledNode,
     file_path: &Path,
     dimensions: (usize, usize),
+    fonts_ctx: &mut FontsContext,
 ) -> LayoutNode<'a> {
-    let mut layout_tree_builder = LayoutTreeBuilder::new(dimensions, file_path);
+    let mut layout_tree_builder = LayoutTreeBuilder::new(dimensions, file_path, fonts_ctx);
 
     let root = layout_tree_builder.build(style_tree);
 
     root
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::layout::box_dimensions::Rectangle;
+    use crate::layout::box_types::{block::Block, inline::Inline, BoxType};
+    use crate::{css, html, style, NodeType, USER_AGENT_STYLESHEET};
+
+    /// Lays out the document in an 800x600 window and returns the border boxes of the elements
+    /// with an id, one for each fragment of their boxes.
+    fn border_boxes(document: &str) -> HashMap<String, Vec<Rectangle>> {
+        let file_path = Path::new("test.html");
+        let dom = html::parse(document);
+        let author_stylesheet = css::parse_author(&dom, file_path);
+        let user_agent_stylesheet = css::parse_ua(USER_AGENT_STYLESHEET);
+        let style_tree = style::build_style_tree(&dom, &author_stylesheet, &user_agent_stylesheet);
+        let root = build_layout_tree(&style_tree, file_path, (800, 600), &mut FontsContext::new());
+        let mut boxes = HashMap::new();
+
+        collect_border_boxes(&root, &mut boxes);
+
+        boxes
+    }
+
+    fn collect_border_boxes(node: &LayoutNode, boxes: &mut HashMap<String, Vec<Rectangle>>) {
+        if let BoxType::Block(Block {
+            node: styled_node, ..
+        })
+        | BoxType::Inline(Inline {
+            node: styled_node, ..
+        }) = &node.box_type
+        {
+            if let NodeType::Element(element) = &styled_node.node.node_type {
+                if let Some(id) = element.attributes().get("id") {
+                    boxes
+                        .entry(id.clone())
+                        .or_default()
+                        .push(node.box_dimensions.border_box());
+                }
+            }
+        }
+
+        for child in &node.children {
+            collect_border_boxes(child, boxes);
+        }
+    }
+
+    #[test]
+    fn test_collapse_sibling_margins() {
+        let boxes = border_boxes(
+            r#"<style>div { height: 10px; margin: 20px 0; }</style>
+            <div id="a"></div><div id="b"></div>
+            <div id="c" style="margin-bottom: 30px"></div><div id="d" style="margin-top: -10px"></div>"#,
+        );
+
+        // The top margin of the first child also collapses with the one of the body.
+        assert_eq!(boxes["a"][0].y, 20.);
+        assert_eq!(boxes["b"][0].y, 50.);
+        assert_eq!(boxes["c"][0].y, 80.);
+        assert_eq!(boxes["d"][0].y, 110.);
+    }
+
+    #[test]
+    fn test_collapse_parent_and_child_margins() {
+        let boxes = border_boxes(
+            r#"<div id="parent" style="margin-top: 10px">
+                <div id="child" style="margin: 30px 0; height: 10px"></div>
+            </div>
+            <div id="next" style="height: 10px"></div>
+            <div id="bordered" style="border-top: 1px solid; margin-top: 10px">
+                <div id="inner" style="margin-top: 30px; height: 10px"></div>
+            </div>"#,
+        );
+
+        assert_eq!(boxes["parent"][0].y, 30.);
+        assert_eq!(boxes["child"][0].y, 30.);
+        assert_eq!(boxes["parent"][0].height, 10.);
+        assert_eq!(boxes["next"][0].y, 70.);
+        // Borders keep the margins of the children inside.
+        assert_eq!(boxes["bordered"][0].y, 90.);
+        assert_eq!(boxes["inner"][0].y, 121.);
+    }
+
+    #[test]
+    fn test_collapse_margins_through_empty_blocks() {
+        let boxes = border_boxes(
+            r#"<div id="a" style="height: 10px; margin-bottom: 10px"></div>
+            <div id="empty" style="margin: 25px 0"></div>
+            <div id="b" style="margin-top: 5px; height: 10px"></div>"#,
+        );
+
+        assert_eq!(boxes["a"][0].y, 8.);
+        assert_eq!(boxes["empty"][0].height, 0.);
+        assert_eq!(boxes["b"][0].y, 43.);
+    }
+
+    #[test]
+    fn test_keep_margins_inside_inline_blocks() {
+        let boxes = border_boxes(
+            r#"<span id="inline-block" style="display: inline-block">
+                <div id="child" style="margin: 20px 0; height: 10px"></div>
+            </span>"#,
+        );
+
+        let inline_block = &boxes["inline-block"][0];
+
+        assert_eq!(boxes["child"][0].y, inline_block.y + 20.);
+        assert_eq!(inline_block.height, 50.);
+    }
+
+    #[test]
+    fn test_give_inline_blocks_their_specified_height() {
+        let boxes = border_boxes(
+            r#"<style>span { display: inline-block; width: 10px; height: 30px; }</style>
+            <span id="empty"></span><span id="text">x</span>"#,
+        );
+
+        assert_eq!(boxes["empty"][0].height, 30.);
+        assert_eq!(boxes["text"][0].height, 30.);
+    }
+
+    #[test]
+    fn test_size_inline_blocks_around_percentage_widths_as_auto() {
+        let boxes = border_boxes(
+            r#"<span id="inline-block" style="display: inline-block">
+                <div id="child" style="width: 100%"><i style="display: inline-block; width: 60px; height: 10px"></i> <i style="display: inline-block; width: 30px; height: 10px"></i></div>
+            </span>"#,
+        );
+
+        let inline_block = &boxes["inline-block"][0];
+
+        assert!(inline_block.width > 90.);
+        assert_eq!(boxes["child"][0].width, inline_block.width);
+        assert_eq!(boxes["child"][0].y, inline_block.y);
+        assert!(inline_block.height < 20.);
+    }
+
+    #[test]
+    fn test_put_inline_box_edges_on_first_and_last_fragments() {
+        let boxes = border_boxes(
+            r#"<style>
+                i { display: inline-block; width: 100px; height: 10px; }
+                span { padding: 0 10px; border: 2px solid; margin: 0 5px; }
+            </style>
+            <div id="container" style="width: 150px">
+                <span id="span"><i id="a"></i> <i id="b"></i> <i id="c"></i></span>
+            </div>"#,
+        );
+
+        let x = boxes["container"][0].x;
+        let fragments = &boxes["span"];
+
+        assert_eq!(fragments.len(), 3);
+        assert_eq!((fragments[0].x, fragments[0].width), (x + 5., 112.));
+        assert_eq!((fragments[1].x, fragments[1].width), (x, 100.));
+        assert_eq!((fragments[2].x, fragments[2].width), (x, 112.));
+        assert_eq!(boxes["a"][0].x, x + 17.);
+        assert_eq!(boxes["b"][0].x, x);
+        assert_eq!(boxes["c"][0].x, x);
+        assert!(fragments[0].y < fragments[1].y && fragments[1].y < fragments[2].y);
+    }
+
+    #[test]
+    fn test_clone_inline_box_edges_on_every_fragment() {
+        let boxes = border_boxes(
+            r#"<style>
+                i { display: inline-block; width: 100px; height: 10px; }
+                span { padding: 0 10px; border: 2px solid; margin: 0 5px; }
+            </style>
+            <div id="container" style="width: 150px">
+                <span id="span" style="box-decoration-break: clone"><i id="a"></i> <i id="b"></i></span>
+            </div>"#,
+        );
+
+        let x = boxes["container"][0].x;
+
+        assert_eq!(boxes["span"].len(), 2);
+
+        for fragment in &boxes["span"] {
+            assert_eq!((fragment.x, fragment.width), (x + 5., 124.));
+        }
+
+        assert_eq!(boxes["a"][0].x, x + 17.);
+        assert_eq!(boxes["b"][0].x, x + 17.);
+    }
+
+    #[test]
+    fn test_align_inline_blocks_vertically() {
+        let boxes = border_boxes(
+            r#"<style>span { display: inline-block; width: 10px; height: 10px; }</style>
+            <div id="container">
+                <span id="tall" style="height: 50px"></span>
+                <span id="top" style="vertical-align: top"></span>
+                <span id="bottom" style="vertical-align: bottom"></span>
+                <span id="baseline"></span>
+                <span id="raised" style="vertical-align: 5px"></span>
+            </div>"#,
+        );
+
+        let container = &boxes["container"][0];
+        let tall = &boxes["tall"][0];
+
+        // Empty inline-blocks sit on the baseline with their bottom margin edge.
+        assert_eq!(boxes["baseline"][0].y + 10., tall.y + 50.);
+        assert_eq!(boxes["raised"][0].y, boxes["baseline"][0].y - 5.);
+        assert_eq!(boxes["top"][0].y, container.y);
+        assert_eq!(boxes["bottom"][0].y + 10., container.y + container.height);
+    }
+
+    #[test]
+    fn test_break_lines_between_inline_blocks() {
+        let boxes = border_boxes(
+            r#"<style>
+                div { width: 150px; }
+                i { display: inline-block; width: 100px; height: 10px; }
+            </style>
+            <div id="wrapped"><i id="a"></i> <i id="b"></i></div>
+            <div id="nowrap" style="white-space: nowrap"><i id="c"></i> <i id="d"></i></div>"#,
+        );
+
+        assert_eq!(boxes["b"][0].x, boxes["wrapped"][0].x);
+        assert!(boxes["b"][0].y > boxes["a"][0].y);
+        assert_eq!(boxes["d"][0].y, boxes["c"][0].y);
+        assert!(boxes["d"][0].x > boxes["c"][0].x + 100.);
+    }
+
+    #[test]
+    fn test_break_lines_at_break_opportunities() {
+        let boxes = border_boxes(
+            r#"<style>div { width: 1px; }</style>
+            <div><span id="word">aaaaaaaaaa</span></div>
+            <div style="word-break: break-all"><span id="break-all">aaaaaaaaaa</span></div>
+            <div style="overflow-wrap: anywhere"><span id="anywhere">aaaaaaaaaa</span></div>
+            <div><span id="hyphen">foo-bar</span></div>
+            <div><span id="soft-hyphen">foo&shy;bar</span></div>
+            <div style="hyphens: none"><span id="no-hyphens">foo&shy;bar</span></div>
+            <div><span id="ideographs">漢字漢字</span></div>
+            <div style="word-break: keep-all"><span id="keep-all">漢字漢字</span></div>"#,
+        );
+
+        // Each line gets a fragment of the span.
+        let lines = |id: &str| boxes[id].len();
+
+        assert_eq!(lines("word"), 1);
+        assert_eq!(lines("break-all"), 10);
+        assert_eq!(lines("anywhere"), 10);
+        assert_eq!(lines("hyphen"), 2);
+        assert_eq!(lines("soft-hyphen"), 2);
+        assert_eq!(lines("no-hyphens"), 1);
+        assert_eq!(lines("ideographs"), 4);
+        assert_eq!(lines("keep-all"), 1);
+    }
+
+    #[test]
+    fn test_place_inline_content_right_to_left() {
+        let boxes = border_boxes(
+            r#"<style>
+                div { width: 300px; }
+                i { display: inline-block; width: 100px; height: 10px; }
+            </style>
+            <div id="rtl" dir="rtl"><i id="a"></i><i id="b"></i></div>
+            <div id="end" dir="rtl" style="text-align: end"><i id="c"></i></div>
+            <div id="isolate"><span dir="rtl"><i id="d"></i><i id="e"></i></span></div>"#,
+        );
+
+        let rtl = &boxes["rtl"][0];
+
+        assert_eq!(boxes["a"][0].x + 100., rtl.x + rtl.width);
+        assert_eq!(boxes["b"][0].x + 100., boxes["a"][0].x);
+        assert_eq!(boxes["c"][0].x, boxes["end"][0].x);
+        assert_eq!(boxes["e"][0].x, boxes["isolate"][0].x);
+        assert_eq!(boxes["d"][0].x, boxes["e"][0].x + 100.);
+    }
+
+    #[test]
+    fn test_align_lines_holding_only_nested_aligned_content() {
+        let boxes = border_boxes(
+            r#"<div id="container" style="width: 60px">
+                <span id="top" style="vertical-align: top">x<b id="bottom" style="vertical-align: bottom">aaaa bbbb cccc dddd</b>y</span>
+            </div>
+            <div style="width: 60px">
+                <span style="vertical-align: top">x<b id="nested" style="vertical-align: top">aaaa bbbb cccc dddd</b>y</span>
+            </div>"#,
+        );
+
+        let container = &boxes["container"][0];
+        let bottom = &boxes["bottom"];
+
+        assert!(bottom.len() > 2);
+
+        for fragment in bottom {
+            assert!(fragment.y >= container.y);
+            assert!(fragment.y + fragment.height <= container.y + container.height);
+        }
+
+        assert!(boxes["nested"].len() > 2);
+    }
+
+    #[test]
+    fn test_lay_out_anonymous_blocks_with_the_styles_of_their_parent() {
+        let boxes = border_boxes(
+            r#"<style>
+                div { width: 300px; }
+                i { display: inline-block; width: 100px; height: 10px; }
+            </style>
+            <div id="anonymous" dir="rtl"><p>x</p><span dir="ltr"><i id="a"></i></span><i id="b"></i></div>
+            <div id="split" dir="rtl"><span dir="ltr"><p>x</p><i id="c"></i></span><i id="d"></i></div>"#,
+        );
+
+        let anonymous = &boxes["anonymous"][0];
+        let split = &boxes["split"][0];
+
+        assert_eq!(boxes["a"][0].x + 100., anonymous.x + anonymous.width);
+        assert_eq!(boxes["b"][0].x + 100., boxes["a"][0].x);
+        assert_eq!(boxes["c"][0].x + 100., split.x + split.width);
+        assert_eq!(boxes["d"][0].x + 100., boxes["c"][0].x);
+    }
+
+    #[test]
+    fn test_split_nested_inline_boxes_around_block_descendants() {
+        let boxes = border_boxes(
+            r#"<style>i { display: inline-block; width: 10px; height: 10px; }</style>
+            <div id="container">
+                <a id="a"><i id="before"></i><em id="em"><i id="inner"></i><div id="block" style="height: 20px"></div><i id="after"></i></em></a>
+            </div>"#,
+        );
+
+        let container = &boxes["container"][0];
+        let block = &boxes["block"][0];
+        let (a, em) = (&boxes["a"], &boxes["em"]);
+
+        assert_eq!((a.len(), em.len()), (2, 2));
+
+        // The block sits between the lines holding the fragments before and after it.
+        assert_eq!((block.x, block.width), (container.x, container.width));
+        assert_eq!(block.y, a[0].y + a[0].height);
+        assert_eq!((a[0].x, a[0].width), (container.x, 20.));
+        assert_eq!((em[0].x, em[0].width), (container.x + 10., 10.));
+        assert_eq!((a[1].x, a[1].y), (container.x, block.y + block.height));
+        assert_eq!((em[1].x, em[1].y), (container.x, block.y + block.height));
+        assert_eq!(boxes["before"][0].x, container.x);
+        assert_eq!(boxes["inner"][0].x, container.x + 10.);
+        assert_eq!(boxes["after"][0].x, container.x);
+    }
+
+    #[test]
+    fn test_inherit_number_line_heights_as_factors_and_others_as_lengths() {
+        let boxes = border_boxes(
+            r#"<style>.parent { font-size: 10px; } .child { font-size: 40px; }</style>
+            <div class="parent" style="line-height: 2"><div id="number" class="child">x</div></div>
+            <div class="parent" style="line-height: 200%"><div id="percentage" class="child">x</div></div>
+            <div class="parent" style="line-height: 20px"><div id="length" class="child">x</div></div>"#,
+        );
+
+        assert_eq!(boxes["number"][0].height, 80.);
+        assert_eq!(boxes["percentage"][0].height, 20.);
+        assert_eq!(boxes["length"][0].height, 20.);
+    }
+
+    #[test]
+    fn test_size_images_from_their_attributes_inline_and_as_blocks() {
+        let boxes = border_boxes(
+            r#"<style>i { display: inline-block; width: 10px; height: 10px; }</style>
+            <div id="line"><i id="before"></i><img id="inline" width="30" height="40"><i id="after"></i></div>
+            <img id="block" style="display: block" width="30" height="40">
+            <div id="next">x</div>"#,
+        );
+
+        let line = &boxes["line"][0];
+        let before = &boxes["before"][0];
+        let inline = &boxes["inline"][0];
+        let block = &boxes["block"][0];
+
+        // Inline images sit on the baseline with their bottom edge, like empty inline-blocks.
+        assert_eq!((inline.width, inline.height), (30., 40.));
+        assert_eq!(inline.x, line.x + 10.);
+        assert_eq!(boxes["after"][0].x, line.x + 40.);
+        assert_eq!(inline.y + inline.height, before.y + before.height);
+
+        assert_eq!((block.x, block.width, block.height), (line.x, 30., 40.));
+        assert_eq!(block.y, line.y + line.height);
+        assert_eq!(boxes["next"][0].y, block.y + block.height);
+    }
+
+    #[test]
+    fn test_share_the_baseline_between_font_sizes() {
+        let boxes = border_boxes(
+            r#"<style>i { display: inline-block; width: 10px; height: 10px; }</style>
+            <div id="line">x<span id="small" style="font-size: 10px">x<i id="a"></i></span><span id="big" style="font-size: 40px">x<i id="b"></i></span></div>"#,
+        );
+
+        let (small, big) = (&boxes["small"][0], &boxes["big"][0]);
+        let (a, b) = (&boxes["a"][0], &boxes["b"][0]);
+        let baseline = a.y + a.height;
+
+        assert_eq!(b.y + b.height, baseline);
+
+        // Ascents scale with the font size, so the baseline splits both content areas alike.
+        let small_ascent = (baseline - small.y) / small.height;
+        let big_ascent = (baseline - big.y) / big.height;
+
+        assert!((small_ascent - big_ascent).abs() < 1e-3);
+        assert_eq!(big.y, boxes["line"][0].y);
+    }
+}
@@ -36,6 +36,17 @@ impl BoxDimensions {
         border_box
     }
 
+    pub(crate) fn margin_box(&self) -> Rectangle {
+        let mut margin_box = self.border_box();
+
+        margin_box.x -= self.margin.left;
+        margin_box.y -= self.margin.top;
+        margin_box.width += self.margin.left + self.margin.right;
+        margin_box.height += self.margin.top + self.margin.bottom;
+
+        margin_box
+    }
+
     pub(crate) fn padding_box(&self) -> Rectangle {
         let mut padding_box = self.content.clone();
 
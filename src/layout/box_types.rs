@@ -8,6 +8,7 @@ use crate::layout::box_types::word::Word;
 
 use crate::layout::box_dimensions::BoxDimensions;
 use crate::layout::layout_node::LayoutNode;
+use crate::painter::fonts_context::FontsContext;
 
 pub(crate) mod anonymous;
 pub(crate) mod block;
@@ -15,13 +16,14 @@ pub(crate) mod inline;
 pub(crate) mod line;
 pub(crate) mod word;
 
-#[derive(PartialEq)]
+#[derive(PartialEq, Default)]
 pub(crate) enum BoxType<'a> {
     Block(Block<'a>),
     Inline(Inline<'a>),
-    Anonymous(Anonymous),
+    Anonymous(Anonymous<'a>),
     Line(Line),
     Word(Word),
+    #[default]
     Temporal,
 }
 
@@ -32,21 +34,20 @@ impl BoxType<'_> {
         containing_block: &BoxDimensions,
         desired_height: Option<f32>,
         file_path: &Path,
+        fonts_ctx: &mut FontsContext,
     ) {
         match self {
-            BoxType::Block(block) => {
-                block.compute_layout(layout_node, containing_block, desired_height, file_path)
-            }
+            BoxType::Block(block) => block.compute_layout(
+                layout_node,
+                containing_block,
+                desired_height,
+                file_path,
+                fonts_ctx,
+            ),
             BoxType::Anonymous(anonymous) => {
-                anonymous.compute_layout(layout_node, containing_block, file_path)
+                anonymous.compute_layout(layout_node, containing_block, file_path, fonts_ctx)
             }
             _ => {}
         }
     }
 }
-
-impl Default for BoxType<'_> {
-    fn default() -> Self {
-        BoxType::Anonymous(Anonymous)
-    }
-}
@@ -1,25 +1,29 @@
 use std::path::Path;
 
-use super::inline::Inline;
 use crate::layout::box_dimensions::BoxDimensions;
-use crate::layout::box_types::BoxType;
 use crate::layout::formatting_context::FormattingContext;
 use crate::layout::layout_node::LayoutNode;
-use crate::style::properties::text_align::TextAlign;
-
-#[derive(Debug, PartialEq, Default)]
-pub(crate) struct Anonymous;
+use crate::painter::fonts_context::FontsContext;
+use crate::style::properties::unicode_bidi::BidiMode;
+use crate::style::types::StyledNode;
+
+#[derive(PartialEq)]
+pub(crate) struct Anonymous<'a> {
+    /// The block the anonymous box is in, whose styles it lays out its content with.
+    pub(crate) node: &'a StyledNode<'a>,
+}
 
-impl Anonymous {
+impl Anonymous<'_> {
     pub(crate) fn compute_layout(
         &self,
         node: &mut LayoutNode,
         containing_block: &BoxDimensions,
         file_path: &Path,
+        fonts_ctx: &mut FontsContext,
     ) {
         self.compute_width(node, containing_block);
         self.compute_position(node, containing_block);
-        self.compute_height(node, file_path);
+        self.compute_height(node, file_path, fonts_ctx);
     }
 
     pub(crate) fn compute_width(&self, node: &mut LayoutNode, containing_block: &BoxDimensions) {
@@ -32,21 +36,19 @@ impl Anonymous {
             containing_block.content.y + containing_block.content.height;
     }
 
-    pub(crate) fn compute_height(&self, node: &mut LayoutNode, file_path: &Path) {
-        let text_alignment = &self.text_alignment(node);
-
-        FormattingContext::Inline.handle(node, text_alignment, None, file_path);
-    }
-
-    pub(crate) fn text_alignment(&self, node: &LayoutNode) -> TextAlign {
-        let child = node
-            .children
-            .first()
-            .expect("Anonymous box must have a child");
-
-        match &child.box_type {
-            BoxType::Inline(Inline { node }) => node.text_align().clone(),
-            _ => panic!("Anonymous box must have an inline child"),
-        }
+    pub(crate) fn compute_height(
+        &self,
+        node: &mut LayoutNode,
+        file_path: &Path,
+        fonts_ctx: &mut FontsContext,
+    ) {
+        FormattingContext::Inline.handle(
+            node,
+            self.node,
+            BidiMode::Normal,
+            None,
+            file_path,
+            fonts_ctx,
+        );
     }
 }
@@ -2,9 +2,10 @@ use std::path::Path;
 
 use image::GenericImageView;
 
-use crate::layout::box_dimensions::BoxDimensions;
+use crate::layout::box_dimensions::{BoxDimensions, Rectangle};
 use crate::layout::formatting_context::FormattingContext;
 use crate::layout::layout_node::LayoutNode;
+use crate::painter::fonts_context::FontsContext;
 use crate::style::types::StyledNode;
 use crate::NodeType;
 
@@ -12,6 +13,8 @@ use crate::NodeType;
 pub(crate) struct Block<'a> {
     pub(crate) node: &'a StyledNode<'a>,
     pub(crate) formatting_context: FormattingContext,
+    /// The inline boxes the block was split out of, whose opacity still applies to it.
+    pub(crate) inline_ancestors: Vec<&'a StyledNode<'a>>,
 }
 
 impl Block<'_> {
@@ -21,10 +24,65 @@ impl Block<'_> {
         containing_block: &BoxDimensions,
         desired_height: Option<f32>,
         file_path: &Path,
+        fonts_ctx: &mut FontsContext,
     ) {
         self.compute_width(node, containing_block, desired_height, file_path);
         self.compute_position(node, containing_block);
-        self.compute_height(node, desired_height, file_path);
+        self.compute_height(node, desired_height, file_path, fonts_ctx);
+    }
+
+    /// Lays out at the top left of the containing block, to be moved onto a line. Auto widths
+    /// shrink to fit and auto margins are zero.
+    pub(crate) fn compute_layout_inline_block(
+        &self,
+        node: &mut LayoutNode,
+        containing_block_width: f32,
+        desired_height: Option<f32>,
+        file_path: &Path,
+        fonts_ctx: &mut FontsContext,
+    ) {
+        let containing_block = BoxDimensions {
+            content: Rectangle {
+                width: containing_block_width,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        self.compute_width(node, &containing_block, desired_height, file_path);
+
+        let dimensions = &mut node.box_dimensions;
+
+        dimensions.margin.left = self.node.margin_left().actual_value(containing_block_width);
+        dimensions.margin.right = self
+            .node
+            .margin_right()
+            .actual_value(containing_block_width);
+
+        if self.node.width().is_auto() {
+            let (min_content, max_content) = self.intrinsic_widths(file_path, fonts_ctx);
+            let available_width =
+                containing_block_width - (dimensions.margin_box().width - dimensions.content.width);
+
+            dimensions.content.width = available_width.max(min_content).min(max_content);
+        }
+
+        self.compute_position(node, &containing_block);
+        self.compute_height(node, desired_height, file_path, fonts_ctx);
+    }
+
+    pub(crate) fn intrinsic_widths(
+        &self,
+        file_path: &Path,
+        fonts_ctx: &mut FontsContext,
+    ) -> (f32, f32) {
+        *self.node.intrinsic_widths.get_or_init(|| {
+            self.formatting_context.intrinsic_widths(
+                LayoutNode::from(self.node),
+                self.node,
+                file_path,
+                fonts_ctx,
+            )
+        })
     }
 
     fn compute_width(
@@ -48,12 +106,12 @@ impl Block<'_> {
             .actual_value(containing_block.content.width);
         let border_left = self
             .node
-            .border_width()
-            .actual_value(self.node.border_style().actual_value());
+            .border_left_width()
+            .actual_value(self.node.border_left_style().actual_value());
         let border_right = self
             .node
-            .border_width()
-            .actual_value(self.node.border_style().actual_value());
+            .border_right_width()
+            .actual_value(self.node.border_right_style().actual_value());
         let mut width = self
             .node
             .width()
@@ -143,7 +201,7 @@ impl Block<'_> {
             .actual_value(containing_block.content.width);
 
         let (intrinsic_width, intrinsic_height) =
-            self.intrinsic_image_dimensions(self.node, file_path);
+            Block::intrinsic_image_dimensions(self.node, file_path);
         let intrinsic_ratio = intrinsic_width / intrinsic_height;
 
         if is_width_auto && is_height_auto {
@@ -155,7 +213,10 @@ impl Block<'_> {
         }
     }
 
-    fn intrinsic_image_dimensions(&self, node: &StyledNode, document_path: &Path) -> (f32, f32) {
+    pub(crate) fn intrinsic_image_dimensions(
+        node: &StyledNode,
+        document_path: &Path,
+    ) -> (f32, f32) {
         let NodeType::Element(element) = &node.node.node_type else {
             panic!("Node is not an element");
         };
@@ -203,12 +264,12 @@ impl Block<'_> {
             .actual_value(containing_block.content.width);
         let border_top = self
             .node
-            .border_width()
-            .actual_value(self.node.border_style().actual_value());
+            .border_top_width()
+            .actual_value(self.node.border_top_style().actual_value());
         let border_bottom = self
             .node
-            .border_width()
-            .actual_value(self.node.border_style().actual_value());
+            .border_bottom_width()
+            .actual_value(self.node.border_bottom_style().actual_value());
 
         node.box_dimensions.margin.top = margin_top;
         node.box_dimensions.margin.bottom = margin_bottom;
@@ -229,12 +290,24 @@ impl Block<'_> {
             + node.box_dimensions.border.top;
     }
 
-    fn compute_height(&self, node: &mut LayoutNode, desired_height: Option<f32>, file_path: &Path) {
+    fn compute_height(
+        &self,
+        node: &mut LayoutNode,
+        desired_height: Option<f32>,
+        file_path: &Path,
+        fonts_ctx: &mut FontsContext,
+    ) {
         if self.node.is_replaced_element() {
             self.compute_height_replaced_element(node, desired_height, file_path);
         } else {
-            self.formatting_context
-                .handle(node, self.node.text_align(), desired_height, file_path);
+            self.formatting_context.handle(
+                node,
+                self.node,
+                self.node.unicode_bidi().actual_value(),
+                desired_height,
+                file_path,
+                fonts_ctx,
+            );
         }
     }
 
@@ -250,7 +323,7 @@ impl Block<'_> {
             node.box_dimensions.content.height = desired_height;
         } else {
             let (intrinsic_width, intrinsic_height) =
-                self.intrinsic_image_dimensions(styled_node, file_path);
+                Block::intrinsic_image_dimensions(styled_node, file_path);
 
             if intrinsic_height == 0.0 {
                 node.box_dimensions.content.height = 0.0;
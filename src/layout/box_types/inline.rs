@@ -1,6 +1,109 @@
+use std::path::Path;
+
+use super::block::Block;
+use crate::layout::box_dimensions::BoxDimensions;
+use crate::layout::layout_node::LayoutNode;
 use crate::style::types::StyledNode;
 
 #[derive(PartialEq)]
 pub(crate) struct Inline<'a> {
     pub(crate) node: &'a StyledNode<'a>,
+    // Cut sides continue on another line and leave out their edges.
+    pub(crate) cut_left: bool,
+    pub(crate) cut_right: bool,
+}
+
+impl<'a> Inline<'a> {
+    pub(crate) fn new(node: &'a StyledNode<'a>) -> Self {
+        Inline {
+            node,
+            cut_left: false,
+            cut_right: false,
+        }
+    }
+
+    /// Percentage heights behave as auto, the height of the containing block depending on
+    /// its lines.
+    pub(crate) fn compute_layout_replaced(
+        &self,
+        node: &mut LayoutNode,
+        containing_block_width: f32,
+        file_path: &Path,
+    ) {
+        let styled_node = self.node;
+
+        self.compute_edges(&mut node.box_dimensions, containing_block_width);
+
+        let dimensions = &mut node.box_dimensions;
+
+        dimensions.margin.top = styled_node
+            .margin_top()
+            .actual_value(containing_block_width);
+        dimensions.margin.bottom = styled_node
+            .margin_bottom()
+            .actual_value(containing_block_width);
+
+        let (intrinsic_width, intrinsic_height) =
+            Block::intrinsic_image_dimensions(styled_node, file_path);
+        let width = (!styled_node.width().is_auto())
+            .then(|| styled_node.width().actual_value(containing_block_width));
+        let height = (!styled_node.height().is_auto() && !styled_node.height().is_percentage())
+            .then(|| styled_node.height().actual_value(0.));
+
+        let (width, height) = match (width, height) {
+            (Some(width), Some(height)) => (width, height),
+            (Some(width), None) if intrinsic_width > 0. => {
+                (width, width * intrinsic_height / intrinsic_width)
+            }
+            (None, Some(height)) if intrinsic_height > 0. => {
+                (height * intrinsic_width / intrinsic_height, height)
+            }
+            (Some(width), None) => (width, intrinsic_height),
+            (None, Some(height)) => (intrinsic_width, height),
+            (None, None) => (intrinsic_width, intrinsic_height),
+        };
+
+        dimensions.content.width = width;
+        dimensions.content.height = height;
+    }
+
+    /// Vertical margins only apply to replaced elements.
+    pub(crate) fn compute_edges(
+        &self,
+        dimensions: &mut BoxDimensions,
+        containing_block_width: f32,
+    ) {
+        let styled_node = self.node;
+
+        dimensions.padding.top = styled_node
+            .padding_top()
+            .actual_value(containing_block_width);
+        dimensions.padding.right = styled_node
+            .padding_right()
+            .actual_value(containing_block_width);
+        dimensions.padding.bottom = styled_node
+            .padding_bottom()
+            .actual_value(containing_block_width);
+        dimensions.padding.left = styled_node
+            .padding_left()
+            .actual_value(containing_block_width);
+        dimensions.border.top = styled_node
+            .border_top_width()
+            .actual_value(styled_node.border_top_style().actual_value());
+        dimensions.border.right = styled_node
+            .border_right_width()
+            .actual_value(styled_node.border_right_style().actual_value());
+        dimensions.border.bottom = styled_node
+            .border_bottom_width()
+            .actual_value(styled_node.border_bottom_style().actual_value());
+        dimensions.border.left = styled_node
+            .border_left_width()
+            .actual_value(styled_node.border_left_style().actual_value());
+        dimensions.margin.right = styled_node
+            .margin_right()
+            .actual_value(containing_block_width);
+        dimensions.margin.left = styled_node
+            .margin_left()
+            .actual_value(containing_block_width);
+    }
 }
@@ -1,2 +1,4 @@
 #[derive(Debug, PartialEq)]
-pub(crate) struct Line;
+pub(crate) struct Line {
+    pub(crate) baseline: f32,
+}
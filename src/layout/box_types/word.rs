@@ -1,10 +1,25 @@
-use crate::css::types::Rgb;
+use font_kit::properties::Style;
+
+use crate::{
+    css::types::Rgb,
+    painter::shaping::GlyphRun,
+    style::properties::{box_shadow::Shadow, text_decoration::Decoration},
+};
 
 #[derive(Debug, PartialEq)]
 pub(crate) struct Word {
     pub(crate) text: String,
+    /// The glyphs of the text, shaped when measuring it.
+    pub(crate) glyph_run: GlyphRun,
+    pub(crate) font_family: Vec<String>,
     pub(crate) font_size: f32,
     pub(crate) line_height: f32,
+    /// The height of the font above the baseline, which the content box starts at.
+    pub(crate) ascent: f32,
     pub(crate) font_weight: u32,
+    pub(crate) font_style: Style,
     pub(crate) color: Rgb,
+    pub(crate) text_shadow: Vec<Shadow>,
+    /// The decorations of the element of the word and of its ancestors.
+    pub(crate) text_decorations: Vec<Decoration>,
 }
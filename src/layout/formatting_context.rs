@@ -1,16 +1,32 @@
-use std::{collections::HashMap, mem::take, path::Path};
-
-use font_kit::{
-    family_name::FamilyName,
-    font::Font,
-    properties::{Properties, Weight},
-    source::SystemSource,
+use std::{
+    collections::{HashMap, HashSet},
+    mem::take,
+    path::Path,
 };
 
+use font_kit::properties::Style;
+use unicode_bidi::{BidiInfo, Level};
+use unicode_linebreak::linebreaks;
+
+use crate::layout::box_dimensions::BoxDimensions;
 use crate::layout::box_types::BoxType;
 use crate::layout::box_types::{block::Block, inline::Inline, word::Word as WordBox};
 use crate::layout::layout_node::{LayoutNode, LayoutNodeFactory};
-use crate::{css::types::Rgb, style::properties::text_align::TextAlign, NodeType};
+use crate::style::types::StyledNode;
+use crate::{
+    css::types::Rgb,
+    painter::{
+        fonts_context::{FontKey, FontsContext},
+        shaping::GlyphRun,
+    },
+    style::properties::{
+        box_decoration_break::BoxDecorationBreakMode, box_shadow::Shadow, direction::TextDirection,
+        hyphens::HyphensMode, overflow_wrap::OverflowWrapMode, tab_size::TabSize,
+        text_decoration::Decoration, unicode_bidi::BidiMode, vertical_align::VerticalAlignMode,
+        white_space_collapse::CollapseMode, word_break::WordBreakMode,
+    },
+    NodeType,
+};
 
 #[derive(Debug, PartialEq)]
 pub(crate) enum FormattingContext {
@@ -22,29 +38,155 @@ impl FormattingContext {
     pub(crate) fn handle(
         &self,
         node: &mut LayoutNode,
-        text_alignment: &TextAlign,
+        container: &StyledNode,
+        unicode_bidi: BidiMode,
         desired_height: Option<f32>,
         file_path: &Path,
+        fonts_ctx: &mut FontsContext,
     ) {
         match self {
-            FormattingContext::Block => self.handle_block(node, desired_height, file_path),
-            FormattingContext::Inline => self.handle_inline(node, text_alignment, desired_height),
+            FormattingContext::Block => {
+                self.handle_block(node, container, desired_height, file_path, fonts_ctx)
+            }
+            FormattingContext::Inline => self.handle_inline(
+                node,
+                container,
+                unicode_bidi,
+                desired_height,
+                file_path,
+                fonts_ctx,
+            ),
         }
     }
 
-    fn handle_block(&self, node: &mut LayoutNode, desired_height: Option<f32>, file_path: &Path) {
+    /// Percentages of the containing block are taken as zero.
+    pub(crate) fn intrinsic_widths(
+        &self,
+        node: LayoutNode,
+        container: &StyledNode,
+        file_path: &Path,
+        fonts_ctx: &mut FontsContext,
+    ) -> (f32, f32) {
+        match self {
+            FormattingContext::Block => node
+                .children
+                .into_iter()
+                .map(|child| child.intrinsic_outer_widths(file_path, fonts_ctx))
+                .fold((0., 0.), |(min, max), (child_min, child_max)| {
+                    (min.max(child_min), max.max(child_max))
+                }),
+            FormattingContext::Inline => {
+                let InlineContent {
+                    mut words, atomics, ..
+                } = WordBuilder::new(fonts_ctx, 0., file_path).generate_vector_from_layout_nodes(
+                    node.children,
+                    container,
+                    container.unicode_bidi().actual_value(),
+                );
+                let widest_line = |words: &[Word], available_width: f32| {
+                    FormattingContext::break_lines(words, available_width)
+                        .iter()
+                        .map(|line| line.iter().map(|(_, width)| width).sum::<f32>())
+                        .fold(0., f32::max)
+                };
+                let min_content = widest_line(&words, 0.);
+
+                // Inline-blocks were laid out at their min-content width.
+                let max_widths: Vec<Option<f32>> = atomics
+                    .into_iter()
+                    .map(|atomic| {
+                        matches!(atomic.box_type, BoxType::Block(_))
+                            .then(|| atomic.intrinsic_outer_widths(file_path, fonts_ctx).1)
+                    })
+                    .collect();
+
+                for word in &mut words {
+                    if let Some(width) = word.atomic.and_then(|index| max_widths[index]) {
+                        word.width = width;
+                    }
+                }
+
+                (min_content, widest_line(&words, f32::INFINITY))
+            }
+        }
+    }
+
+    /// Margins collapsing through the top or bottom of the container become its own, unless it
+    /// is the root of a block formatting context.
+    fn handle_block(
+        &self,
+        node: &mut LayoutNode,
+        container: &StyledNode,
+        desired_height: Option<f32>,
+        file_path: &Path,
+        fonts_ctx: &mut FontsContext,
+    ) {
+        let is_root = container.establishes_block_formatting_context();
+        let BoxDimensions {
+            padding, border, ..
+        } = &node.box_dimensions;
+        let collapses_top = !is_root && border.top == 0. && padding.top == 0.;
+        let collapses_bottom =
+            !is_root && desired_height.is_none() && border.bottom == 0. && padding.bottom == 0.;
+
+        let mut top_margin = CollapsedMargin::default();
+        // The margins adjoining the bottom of the content laid out so far.
+        let mut margin = CollapsedMargin::default();
+        let mut is_at_top = collapses_top;
+
+        top_margin.add(node.box_dimensions.margin.top);
+
         for child in &mut node.children {
             let child_desired_height = child.compute_desired_height(desired_height);
 
-            child.compute_layout(&node.box_dimensions, child_desired_height, file_path);
+            child.compute_layout(
+                &node.box_dimensions,
+                child_desired_height,
+                file_path,
+                fonts_ctx,
+            );
+            margin.add(child.box_dimensions.margin.top);
+
+            let border_box = child.box_dimensions.border_box();
+            let collapses_through = child.collapses_through();
+
+            if is_at_top && !collapses_through {
+                top_margin.merge(&margin);
+                margin = CollapsedMargin::default();
+            }
+
+            let y = node.box_dimensions.content.y
+                + node.box_dimensions.content.height
+                + if is_at_top { 0. } else { margin.value() };
+
+            child.translate(0., y - border_box.y);
+
+            if collapses_through {
+                margin.add(child.box_dimensions.margin.bottom);
+                continue;
+            }
+
+            node.box_dimensions.content.height =
+                y + border_box.height - node.box_dimensions.content.y;
+            margin = CollapsedMargin::default();
+            margin.add(child.box_dimensions.margin.bottom);
+            is_at_top = false;
+        }
+
+        if is_at_top {
+            top_margin.merge(&margin);
+            margin = CollapsedMargin::default();
+        }
 
-            node.box_dimensions.content.height += child.box_dimensions.margin.top
-                + child.box_dimensions.border.top
-                + child.box_dimensions.padding.top
-                + child.box_dimensions.content.height
-                + child.box_dimensions.padding.bottom
-                + child.box_dimensions.border.bottom
-                + child.box_dimensions.margin.bottom;
+        if collapses_top {
+            node.box_dimensions.margin.top = top_margin.value();
+        }
+
+        if collapses_bottom {
+            margin.add(node.box_dimensions.margin.bottom);
+            node.box_dimensions.margin.bottom = margin.value();
+        } else {
+            node.box_dimensions.content.height += margin.value();
         }
 
         if let Some(height) = desired_height {
@@ -52,11 +194,16 @@ impl FormattingContext {
         }
     }
 
+    /// Lines are tall enough for their words and the strut of the container, each with half
+    /// its leading above and below.
     fn handle_inline(
         &self,
         node: &mut LayoutNode,
-        text_alignment: &TextAlign,
+        container: &StyledNode,
+        unicode_bidi: BidiMode,
         desired_height: Option<f32>,
+        file_path: &Path,
+        fonts_ctx: &mut FontsContext,
     ) {
         if node.children.is_empty()
             || matches!(node.children[0].box_type, BoxType::Block(Block { node, .. }, ..) if node.is_empty_text_node())
@@ -68,255 +215,1469 @@ impl FormattingContext {
         let containing_block_x = node.box_dimensions.content.x;
         let containing_block_y = node.box_dimensions.content.y;
 
-        let mut current_line = LayoutNodeFactory::line_box();
-        current_line.box_dimensions.content.width = 0.0;
-        current_line.box_dimensions.content.x = containing_block_x;
+        let (strut_ascent, strut_descent, strut_line_height) =
+            WordBuilder::font_metrics(fonts_ctx, container);
+        let strut = FormattingContext::line_extents(strut_ascent, strut_descent, strut_line_height);
 
-        let words = WordBuilder::generate_vector_from_layout_nodes(take(&mut node.children));
+        let InlineContent {
+            words,
+            atomics,
+            inline_boxes,
+            paragraph_level,
+        } = WordBuilder::new(fonts_ctx, containing_block_width, file_path)
+            .generate_vector_from_layout_nodes(take(&mut node.children), container, unicode_bidi);
+        let mut atomics: Vec<Option<LayoutNode>> = atomics.into_iter().map(Some).collect();
+        let mut line_contents = Vec::new();
+        let mut acc_height = 0.0;
 
-        let mut i = 0;
+        let mut lines = FormattingContext::break_lines(&words, containing_block_width)
+            .into_iter()
+            .peekable();
 
-        while i < words.len() {
-            let word = &words[i];
+        while let Some(line) = lines.next() {
+            // Inline boxes whose decorations are cloned have their edges on every line.
+            let (starts, ends) = FormattingContext::clone_boundaries(&line, &inline_boxes);
+            let line: Vec<(&Word, f32)> = starts
+                .iter()
+                .map(|word| (word, word.width))
+                .chain(line)
+                .chain(ends.iter().map(|word| (word, word.width)))
+                .collect();
 
-            if (current_line.children.is_empty() && !word.text.trim().is_empty())
-                || (current_line.box_dimensions.content.width + word.width
-                    <= containing_block_width
-                    && !current_line.children.is_empty())
-            {
-                let height = word.font_size;
-                let width = word.width;
-                let mut word = LayoutNodeFactory::word_box(
-                    word.text.clone(),
+            let metrics = LineMetrics::new(&line, strut, &inline_boxes);
+            let line_y = containing_block_y + acc_height;
+            let mut line_box = LayoutNodeFactory::line_box(line_y + metrics.baseline);
+
+            line_box.box_dimensions.content.x = containing_block_x;
+            line_box.box_dimensions.content.y = line_y;
+            line_box.box_dimensions.content.height = metrics.height;
+            acc_height += metrics.height;
+
+            let is_wrapped = lines.peek().is_some();
+            let last = line
+                .iter()
+                .rposition(|(word, _)| word.boundary.is_none())
+                .unwrap_or(0);
+            let mut previous: Option<(usize, &Word)> = None;
+            let mut leaves = Vec::new();
+
+            for (i, word, mut width) in FormattingContext::reorder_line(line, paragraph_level) {
+                let mut text = word.text.clone();
+                let mut glyph_run = word.glyph_run.clone();
+
+                // Soft hyphens are shown where the line wraps after them, which is on the left
+                // of right-to-left words.
+                if word.soft_hyphen && i == last && is_wrapped {
+                    text.push('-');
+                    width += word.hyphen.width;
+
+                    if word.bidi_level.is_rtl() {
+                        glyph_run = word.hyphen.clone();
+                        glyph_run.extend(&word.glyph_run);
+                    } else {
+                        glyph_run.extend(&word.hyphen);
+                    }
+                }
+
+                // The pieces of a word that end up next to each other are drawn together.
+                let joins_previous = previous.is_some_and(|(j, previous)| {
+                    previous.bidi_level == word.bidi_level
+                        && if word.bidi_level.is_rtl() {
+                            i + 1 == j && word.joins_next
+                        } else {
+                            j + 1 == i && previous.joins_next
+                        }
+                });
+
+                previous = Some((i, word));
+
+                if joins_previous {
+                    let previous_box = line_box.children.last_mut().unwrap();
+
+                    if let BoxType::Word(WordBox {
+                        text: previous_text,
+                        glyph_run: previous_run,
+                        ..
+                    }) = &mut previous_box.box_type
+                    {
+                        previous_text.push_str(&text);
+                        previous_run.extend(&glyph_run);
+                    }
+
+                    previous_box.box_dimensions.content.width += width;
+                    line_box.box_dimensions.content.width += width;
+                    continue;
+                }
+
+                leaves.push((
+                    word.inline_boxes.clone(),
+                    word.boundary
+                        .map(|boundary| boundary.is_left(word.bidi_level)),
+                ));
+
+                // Replaced elements and inline-blocks sit on the line with their margins, and
+                // are moved there along with their content.
+                if let Some(index) = word.atomic {
+                    let mut atomic = atomics[index].take().expect("Atomic inline placed twice");
+                    let BoxDimensions {
+                        margin,
+                        border,
+                        padding,
+                        content,
+                    } = &atomic.box_dimensions;
+
+                    let x = line_box.box_dimensions.content.width
+                        + containing_block_x
+                        + margin.left
+                        + border.left
+                        + padding.left;
+                    let y = line_y + metrics.baseline(&word.vertical_position) - word.ascent
+                        + margin.top
+                        + border.top
+                        + padding.top;
+
+                    atomic.translate(x - content.x, y - content.y);
+                    line_box.box_dimensions.content.width += width;
+                    line_box.children.push(atomic);
+                    continue;
+                }
+
+                let mut word_box = LayoutNodeFactory::word_box(
+                    text,
+                    glyph_run,
+                    word.font_family.clone(),
                     word.font_size,
                     word.line_height,
+                    word.ascent,
                     word.font_weight,
+                    word.font_style,
                     word.color.clone(),
+                    word.text_shadow.clone(),
+                    word.text_decorations.clone(),
                 );
 
-                word.box_dimensions.content.width = width;
-                word.box_dimensions.content.height = height;
-                word.box_dimensions.content.x =
-                    current_line.box_dimensions.content.width + containing_block_x;
+                word_box.box_dimensions.content.width = width;
+                word_box.box_dimensions.content.height = word.ascent + word.descent;
+                word_box.box_dimensions.content.x =
+                    line_box.box_dimensions.content.width + containing_block_x;
+                word_box.box_dimensions.content.y =
+                    line_y + metrics.baseline(&word.vertical_position) - word.ascent;
 
-                current_line.box_dimensions.content.width += width;
-                current_line.children.push(word);
-                i += 1;
-            } else if word.text.trim().is_empty() {
-                i += 1;
-            } else {
-                node.children.push(current_line);
-                current_line = LayoutNodeFactory::line_box();
-                current_line.box_dimensions.content.width = 0.0;
-                current_line.box_dimensions.content.x = containing_block_x;
+                line_box.box_dimensions.content.width += width;
+                line_box.children.push(word_box);
             }
+
+            node.children.push(line_box);
+            line_contents.push((leaves, metrics));
+        }
+
+        let direction = if paragraph_level.is_rtl() {
+            TextDirection::Rtl
+        } else {
+            TextDirection::Ltr
+        };
+
+        container.text_align().apply(node, direction);
+
+        for (line, (leaves, metrics)) in node.children.iter_mut().zip(line_contents) {
+            FormattingContext::nest_fragments(line, leaves, &metrics, &inline_boxes);
         }
 
-        if !current_line.children.is_empty() {
-            node.children.push(current_line);
+        if let Some(height) = desired_height {
+            node.box_dimensions.content.height = height;
+        } else {
+            node.box_dimensions.content.height = acc_height;
         }
+    }
+
+    /// Boxes with `box-decoration-break: clone` get their edges on every line they are on.
+    fn clone_boundaries(
+        line: &[(&Word, f32)],
+        inline_boxes: &[InlineBox],
+    ) -> (Vec<Word>, Vec<Word>) {
+        let (Some((first, _)), Some((last, _))) = (line.first(), line.last()) else {
+            return (Vec::new(), Vec::new());
+        };
+
+        let boundaries = |word: &Word, boundary: Boundary| -> Vec<Word> {
+            let begins = word.boundary == Some(boundary);
+            let continuing = word.inline_boxes.len() - begins as usize;
+
+            (0..continuing)
+                .filter(|&depth| inline_boxes[word.inline_boxes[depth]].clone)
+                .map(|depth| {
+                    let inline_box = &inline_boxes[word.inline_boxes[depth]];
+
+                    Word {
+                        text: String::new(),
+                        glyph_run: GlyphRun::default(),
+                        width: inline_box.edges_width(boundary, word.bidi_level),
+                        line_height: inline_box.line_height,
+                        ascent: inline_box.ascent,
+                        descent: inline_box.descent,
+                        inline_boxes: word.inline_boxes[..=depth].to_vec(),
+                        boundary: Some(boundary),
+                        vertical_position: inline_box.vertical_position,
+                        joins_next: false,
+                        soft_hyphen: false,
+                        atomic: None,
+                        ..word.clone()
+                    }
+                })
+                .collect()
+        };
+
+        let mut ends = boundaries(last, Boundary::End);
+
+        ends.reverse();
+
+        (boundaries(first, Boundary::Start), ends)
+    }
+
+    /// Fragments leave out their edges on the sides where their box continues on another line.
+    fn nest_fragments<'a>(
+        line: &mut LayoutNode<'a>,
+        leaves: Vec<(Vec<usize>, Option<bool>)>,
+        metrics: &LineMetrics,
+        inline_boxes: &[InlineBox<'a>],
+    ) {
+        let line_y = line.box_dimensions.content.y;
+        let mut open: Vec<Fragment> = Vec::new();
+
+        for (leaf, (ids, is_left_boundary)) in take(&mut line.children).into_iter().zip(leaves) {
+            let common = open
+                .iter()
+                .zip(&ids)
+                .take_while(|(fragment, id)| fragment.id == **id)
+                .count();
+
+            while open.len() > common {
+                FormattingContext::close_fragment(&mut open, line, metrics, line_y, inline_boxes);
+            }
+
+            for id in &ids[common..] {
+                open.push(Fragment::new(*id));
+            }
+
+            let margin_box = leaf.box_dimensions.margin_box();
 
-        // We clean space at the end of the lines if it exists
-        for line in &mut node.children {
-            let last_word = line.children.last().expect("Expected last word");
-            let BoxType::Word(WordBox { text, .. }) = &last_word.box_type else {
-                panic!("Expected word box type");
+            for fragment in &mut open {
+                fragment.include(margin_box.x, margin_box.x + margin_box.width);
+            }
+
+            let Some(fragment) = open.last_mut() else {
+                line.children.push(leaf);
+                continue;
             };
 
-            if text.trim().is_empty() {
-                line.box_dimensions.content.width -= last_word.box_dimensions.content.width;
-                line.children.pop();
+            match is_left_boundary {
+                Some(true) => fragment.has_left_edge = true,
+                Some(false) => fragment.has_right_edge = true,
+                None => fragment.children.push(leaf),
             }
         }
 
-        text_alignment.apply(node);
+        while !open.is_empty() {
+            FormattingContext::close_fragment(&mut open, line, metrics, line_y, inline_boxes);
+        }
+    }
 
-        let mut acc_height = 0.0;
+    fn close_fragment<'a>(
+        open: &mut Vec<Fragment<'a>>,
+        line: &mut LayoutNode<'a>,
+        metrics: &LineMetrics,
+        line_y: f32,
+        inline_boxes: &[InlineBox<'a>],
+    ) {
+        let fragment = open.pop().unwrap();
+        let inline_box = &inline_boxes[fragment.id];
+        let edges = &inline_box.box_dimensions;
+        let mut node = LayoutNodeFactory::inline_box(
+            inline_box.node,
+            !fragment.has_left_edge,
+            !fragment.has_right_edge,
+        );
+        let dimensions = &mut node.box_dimensions;
 
-        for line in &mut node.children {
-            let mut max = 0.0;
-            let mut max_font_size = 0.0;
+        if fragment.has_left_edge {
+            dimensions.margin.left = edges.margin.left;
+            dimensions.border.left = edges.border.left;
+            dimensions.padding.left = edges.padding.left;
+        }
 
-            for word in &line.children {
-                let BoxType::Word(WordBox { line_height, .. }) = &word.box_type else {
-                    panic!("Expected word box type");
-                };
+        if fragment.has_right_edge {
+            dimensions.margin.right = edges.margin.right;
+            dimensions.border.right = edges.border.right;
+            dimensions.padding.right = edges.padding.right;
+        }
+
+        dimensions.border.top = edges.border.top;
+        dimensions.border.bottom = edges.border.bottom;
+        dimensions.padding.top = edges.padding.top;
+        dimensions.padding.bottom = edges.padding.bottom;
+
+        dimensions.content.x = fragment.left
+            + dimensions.margin.left
+            + dimensions.border.left
+            + dimensions.padding.left;
+        dimensions.content.width = (fragment.right
+            - dimensions.content.x
+            - dimensions.margin.right
+            - dimensions.border.right
+            - dimensions.padding.right)
+            .max(0.);
+        dimensions.content.y =
+            line_y + metrics.baseline(&inline_box.vertical_position) - inline_box.ascent;
+        dimensions.content.height = inline_box.ascent + inline_box.descent;
+
+        node.children = fragment.children;
+
+        match open.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => line.children.push(node),
+        }
+    }
+
+    /// Returns the ascent and descent of an inline box, each with half the leading.
+    fn line_extents(ascent: f32, descent: f32, line_height: f32) -> (f32, f32) {
+        let half_leading = (line_height - (ascent + descent)) / 2.;
+
+        (ascent + half_leading, descent + half_leading)
+    }
+
+    /// Preserved line breaks stay as the last word of their line, so empty lines keep their
+    /// height.
+    fn break_lines(words: &[Word], available_width: f32) -> Vec<Vec<(&Word, f32)>> {
+        let mut lines = Vec::new();
+        let mut line: Vec<(&Word, f32)> = Vec::new();
+        let mut line_width = 0.;
+        let mut i = 0;
+
+        while i < words.len() {
+            // Collapsible spaces are removed at the start of wrapped lines.
+            if line.is_empty() && words[i].is_collapsible_space() {
+                i += 1;
+                continue;
+            }
+
+            let mut end = WordBuilder::segment_end(words, i);
+            let mut segment = Vec::new();
+            let (mut x, mut fit_width) = (line_width, line_width);
+            let mut emergency_end = None;
 
-                if word.box_dimensions.content.height > max {
-                    max = word.box_dimensions.content.height * line_height;
+            for (j, word) in words[i..end].iter().enumerate() {
+                let width = word.width_at(x);
+
+                segment.push((word, width));
+                x += width;
+
+                // Spaces hanging at the end of the line may overflow it.
+                if !word.hangs() {
+                    fit_width = x;
+                }
+
+                if word.soft_hyphen {
+                    fit_width += word.hyphen.width;
                 }
 
-                if word.box_dimensions.content.height > max_font_size {
-                    max_font_size = word.box_dimensions.content.height;
+                if word.wrap_after == WrapOpportunity::Emergency
+                    && (fit_width <= available_width || emergency_end.is_none())
+                {
+                    emergency_end = Some((i + j + 1, x));
                 }
             }
 
-            line.box_dimensions.content.height = max;
-            line.box_dimensions.content.y = containing_block_y + acc_height;
-            acc_height += max;
+            if fit_width > available_width {
+                if !line.is_empty() {
+                    lines.push(FormattingContext::trim_line(take(&mut line)));
+                    line_width = 0.;
+                    continue;
+                }
 
-            let initial_y = line.box_dimensions.content.y + line.box_dimensions.content.height
-                - ((line.box_dimensions.content.height * 1.1 - max_font_size) / 2.);
+                if let Some((emergency_end, emergency_x)) = emergency_end {
+                    segment.truncate(emergency_end - i);
+                    end = emergency_end;
+                    x = emergency_x;
+                }
+            }
 
-            for word in &mut line.children {
-                word.box_dimensions.content.y = initial_y;
+            let ends_with_break = words[i..end]
+                .iter()
+                .rev()
+                .find(|word| word.boundary.is_none())
+                .is_some_and(Word::is_forced_break);
+
+            line.extend(segment);
+            line_width = x;
+            i = end;
+
+            if ends_with_break || fit_width > available_width {
+                lines.push(FormattingContext::trim_line(take(&mut line)));
+                line_width = 0.;
             }
         }
 
-        if let Some(height) = desired_height {
-            node.box_dimensions.content.height = height;
+        if !line.is_empty() {
+            lines.push(FormattingContext::trim_line(line));
+        }
+
+        lines
+    }
+
+    /// The white space ending the line and preceding its tabs is put back to the paragraph
+    /// level before reversing.
+    fn reorder_line(line: Vec<(&Word, f32)>, paragraph_level: Level) -> Vec<(usize, &Word, f32)> {
+        let mut levels: Vec<u8> = line
+            .iter()
+            .map(|(word, _)| word.bidi_level.number())
+            .collect();
+
+        let mut is_trailing = true;
+
+        for (level, (word, _)) in levels.iter_mut().zip(&line).rev() {
+            if word.is_tab() || word.is_forced_break() {
+                is_trailing = true;
+            } else if !word.is_space() && word.boundary.is_none() {
+                is_trailing = false;
+            }
+
+            if is_trailing {
+                *level = paragraph_level.number();
+            }
+        }
+
+        let mut order: Vec<usize> = (0..line.len()).collect();
+        let highest = levels.iter().copied().max().unwrap_or(0);
+
+        if let Some(lowest_odd) = levels.iter().copied().filter(|level| level % 2 == 1).min() {
+            for level in (lowest_odd..=highest).rev() {
+                let mut i = 0;
+
+                while i < order.len() {
+                    let start = i;
+
+                    while i < order.len() && levels[order[i]] >= level {
+                        i += 1;
+                    }
+
+                    order[start..i].reverse();
+                    i += 1;
+                }
+            }
+        }
+
+        order
+            .into_iter()
+            .map(|i| (i, line[i].0, line[i].1))
+            .collect()
+    }
+
+    /// Hanging spaces can sit among the ends of inline boxes.
+    fn trim_line(mut line: Vec<(&Word, f32)>) -> Vec<(&Word, f32)> {
+        let mut i = line.len();
+
+        while i > 0 && line[i - 1].0.boundary.is_some() {
+            i -= 1;
+        }
+
+        if i > 0 && line[i - 1].0.is_forced_break() {
+            i -= 1;
+        }
+
+        while i > 0 {
+            let word = line[i - 1].0;
+
+            if word.hangs() {
+                line.remove(i - 1);
+            } else if word.boundary.is_none() {
+                break;
+            }
+
+            i -= 1;
+        }
+
+        line
+    }
+}
+
+/// Adjoining margins collapsing into one, as wide as the largest positive margin minus the
+/// largest negative one.
+#[derive(Default)]
+struct CollapsedMargin {
+    positive: f32,
+    negative: f32,
+}
+
+impl CollapsedMargin {
+    fn add(&mut self, margin: f32) {
+        self.positive = self.positive.max(margin);
+        self.negative = self.negative.min(margin);
+    }
+
+    fn merge(&mut self, other: &CollapsedMargin) {
+        self.add(other.positive);
+        self.add(other.negative);
+    }
+
+    fn value(&self) -> f32 {
+        self.positive + self.negative
+    }
+}
+
+struct LineMetrics {
+    baseline: f32,
+    height: f32,
+    // The baselines of the boxes aligned with the top or bottom of the line.
+    aligned_baselines: HashMap<(LineEdge, usize), f32>,
+}
+
+impl LineMetrics {
+    fn new(line: &[(&Word, f32)], strut: (f32, f32), inline_boxes: &[InlineBox]) -> Self {
+        let mut baseline_extents = strut;
+        let mut aligned_extents: HashMap<(LineEdge, usize), (f32, f32)> = HashMap::new();
+        let mut include = |position: &VerticalPosition, (above, below): (f32, f32)| {
+            let (above, below) = (above + position.shift, below - position.shift);
+            let extents = match position.edge {
+                LineEdge::Baseline => &mut baseline_extents,
+                edge => aligned_extents
+                    .entry((edge, position.id))
+                    .or_insert((above, below)),
+            };
+
+            *extents = (extents.0.max(above), extents.1.max(below));
+        };
+
+        for (word, _) in line {
+            include(&word.vertical_position, word.line_extents());
+
+            // Aligned boxes get a baseline even on lines that only hold the content of the
+            // boxes nested in them.
+            for inline_box in word.inline_boxes.iter().map(|&id| &inline_boxes[id]) {
+                if inline_box.vertical_position.edge != LineEdge::Baseline {
+                    include(&inline_box.vertical_position, inline_box.line_extents());
+                }
+            }
+        }
+
+        let height = aligned_extents.values().fold(
+            baseline_extents.0 + baseline_extents.1,
+            |height, extents| height.max(extents.0 + extents.1),
+        );
+
+        let aligned_baselines = aligned_extents
+            .into_iter()
+            .map(|((edge, id), (above, below))| match edge {
+                LineEdge::Top => ((edge, id), above),
+                _ => ((edge, id), height - below),
+            })
+            .collect();
+
+        Self {
+            baseline: baseline_extents.0,
+            height,
+            aligned_baselines,
+        }
+    }
+
+    fn baseline(&self, position: &VerticalPosition) -> f32 {
+        let baseline = match position.edge {
+            LineEdge::Baseline => self.baseline,
+            edge => self.aligned_baselines[&(edge, position.id)],
+        };
+
+        baseline - position.shift
+    }
+}
+
+pub(crate) struct WordBuilder<'a, 'b> {
+    fonts_ctx: &'b mut FontsContext,
+    containing_block_width: f32,
+    file_path: &'b Path,
+    words: Vec<Word>,
+    atomics: Vec<LayoutNode<'a>>,
+    inline_boxes: Vec<InlineBox<'a>>,
+}
+
+pub(crate) struct InlineContent<'a> {
+    pub(crate) words: Vec<Word>,
+    pub(crate) atomics: Vec<LayoutNode<'a>>,
+    pub(crate) inline_boxes: Vec<InlineBox<'a>>,
+    pub(crate) paragraph_level: Level,
+}
+
+pub(crate) struct InlineBox<'a> {
+    node: &'a StyledNode<'a>,
+    // Only the padding, borders and horizontal margins.
+    box_dimensions: BoxDimensions,
+    ascent: f32,
+    descent: f32,
+    line_height: f32,
+    vertical_position: VerticalPosition,
+    clone: bool,
+}
+
+impl InlineBox<'_> {
+    fn line_extents(&self) -> (f32, f32) {
+        FormattingContext::line_extents(self.ascent, self.descent, self.line_height)
+    }
+
+    /// Boxes start on the right in right-to-left text.
+    fn edges_width(&self, boundary: Boundary, bidi_level: Level) -> f32 {
+        let BoxDimensions {
+            margin,
+            border,
+            padding,
+            ..
+        } = &self.box_dimensions;
+
+        if boundary.is_left(bidi_level) {
+            margin.left + border.left + padding.left
         } else {
-            node.box_dimensions.content.height = acc_height;
+            margin.right + border.right + padding.right
         }
     }
 }
 
-pub(crate) struct WordBuilder;
+struct Fragment<'a> {
+    id: usize,
+    left: f32,
+    right: f32,
+    has_left_edge: bool,
+    has_right_edge: bool,
+    children: Vec<LayoutNode<'a>>,
+}
 
-#[derive(Debug)]
+impl Fragment<'_> {
+    fn new(id: usize) -> Self {
+        Fragment {
+            id,
+            left: f32::INFINITY,
+            right: f32::NEG_INFINITY,
+            has_left_edge: false,
+            has_right_edge: false,
+            children: Vec::new(),
+        }
+    }
+
+    fn include(&mut self, left: f32, right: f32) {
+        self.left = self.left.min(left);
+        self.right = self.right.max(right);
+    }
+}
+
+const OBJECT_REPLACEMENT: char = '\u{fffc}';
+
+const SOFT_HYPHEN: char = '\u{ad}';
+
+#[derive(Debug, Clone)]
 pub(crate) struct Word {
     pub(crate) text: String,
+    pub(crate) glyph_run: GlyphRun,
     pub(crate) width: f32,
+    /// The line height of the word, in pixels.
     pub(crate) line_height: f32,
+    /// The ascent and descent of the font of the word, above and below its baseline.
+    pub(crate) ascent: f32,
+    pub(crate) descent: f32,
+    pub(crate) vertical_position: VerticalPosition,
+    /// The replaced element or inline-block the word stands for.
+    pub(crate) atomic: Option<usize>,
+    pub(crate) font_family: Vec<String>,
     pub(crate) font_size: f32,
     pub(crate) font_weight: u32,
+    pub(crate) font_style: Style,
     pub(crate) color: Rgb,
+    pub(crate) text_shadow: Vec<Shadow>,
+    pub(crate) text_decorations: Vec<Decoration>,
+    pub(crate) letter_spacing: f32,
+    pub(crate) word_spacing: f32,
+    pub(crate) collapse: CollapseMode,
+    /// Whether lines may wrap at the spaces and tabs of the word.
+    pub(crate) wrap: bool,
+    pub(crate) tab_size: TabSize,
+    pub(crate) word_break: WordBreakMode,
+    pub(crate) overflow_wrap: OverflowWrapMode,
+    pub(crate) hyphens: HyphensMode,
+    pub(crate) wrap_after: WrapOpportunity,
+    /// Whether the word is a piece of a longer one, which the next word continues.
+    pub(crate) joins_next: bool,
+    /// Whether the word ends with a soft hyphen, shown when the line wraps after it.
+    pub(crate) soft_hyphen: bool,
+    pub(crate) hyphen: GlyphRun,
+    /// The inline elements around the word that embed or isolate their text.
+    pub(crate) bidi_embeddings: Vec<BidiEmbedding>,
+    pub(crate) bidi_level: Level,
+    /// From the outermost one.
+    pub(crate) inline_boxes: Vec<usize>,
+    /// The start or end of the innermost inline box, taking the room of its edges.
+    pub(crate) boundary: Option<Boundary>,
+}
+
+/// An inline element embedding or isolating its text, identified by the number of words
+/// before it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BidiEmbedding {
+    id: usize,
+    opening: &'static str,
+    closing: &'static str,
+}
+
+/// Boxes aligned with the top or bottom of the line are identified by the number of words
+/// before them.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct VerticalPosition {
+    edge: LineEdge,
+    id: usize,
+    shift: f32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+enum LineEdge {
+    #[default]
+    Baseline,
+    Top,
+    Bottom,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Boundary {
+    Start,
+    End,
+}
+
+impl Boundary {
+    fn is_left(self, bidi_level: Level) -> bool {
+        (self == Boundary::Start) != bidi_level.is_rtl()
+    }
+}
+
+/// Whether lines may wrap after a word.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum WrapOpportunity {
+    None,
+    /// Only when the word would otherwise overflow the line, following `overflow-wrap`.
+    Emergency,
+    Soft,
+}
+
+impl Word {
+    /// Preserved line breaks are kept as words of their own, as are spaces and tabs.
+    pub(crate) fn is_forced_break(&self) -> bool {
+        self.text == "\n"
+    }
+
+    fn is_space(&self) -> bool {
+        self.text == " "
+    }
+
+    fn is_tab(&self) -> bool {
+        self.text == "\t"
+    }
+
+    fn is_collapsible_space(&self) -> bool {
+        self.is_space()
+            && matches!(
+                self.collapse,
+                CollapseMode::Collapse | CollapseMode::PreserveBreaks
+            )
+    }
+
+    /// Whether the word is dropped when it ends a line, instead of taking room in it.
+    fn hangs(&self) -> bool {
+        self.is_collapsible_space()
+            || ((self.is_space() || self.is_tab())
+                && self.wrap
+                && self.collapse == CollapseMode::Preserve)
+    }
+
+    /// Tells how lines may wrap between two characters of the word, given whether the Unicode
+    /// line breaking algorithm finds an opportunity between them.
+    fn wrap_opportunity(&self, is_opportunity: bool, before: char, after: char) -> WrapOpportunity {
+        let is_soft_hyphen = before == SOFT_HYPHEN;
+        let is_letter_pair = before.is_alphanumeric() && after.is_alphanumeric();
+
+        let is_soft = match self.word_break {
+            _ if is_soft_hyphen && self.hyphens == HyphensMode::None => false,
+            WordBreakMode::BreakAll => is_opportunity || is_letter_pair,
+            WordBreakMode::KeepAll => is_opportunity && !is_letter_pair,
+            WordBreakMode::Normal | WordBreakMode::BreakWord => is_opportunity,
+        };
+
+        if is_soft {
+            WrapOpportunity::Soft
+        } else if self.overflow_wrap != OverflowWrapMode::Normal
+            || self.word_break == WordBreakMode::BreakWord
+        {
+            WrapOpportunity::Emergency
+        } else {
+            WrapOpportunity::None
+        }
+    }
+
+    /// Atomic boxes reach as far as their margins, without leading.
+    fn line_extents(&self) -> (f32, f32) {
+        if self.atomic.is_some() {
+            return (self.ascent, self.descent);
+        }
+
+        FormattingContext::line_extents(self.ascent, self.descent, self.line_height)
+    }
+
+    /// Builds a piece of the word with the given text, without its soft hyphens.
+    fn piece(&self, text: &str, wrap_after: WrapOpportunity, joins_next: bool) -> Word {
+        Word {
+            text: text.replace(SOFT_HYPHEN, ""),
+            wrap_after,
+            joins_next,
+            soft_hyphen: text.ends_with(SOFT_HYPHEN)
+                && wrap_after == WrapOpportunity::Soft
+                && self.hyphens != HyphensMode::None,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the width of the word when it starts at `x` from the start of the line. Tabs
+    /// reach the next tab stop, their width being the distance between stops.
+    fn width_at(&self, x: f32) -> f32 {
+        if self.is_tab() && self.width > 0. {
+            self.width - x % self.width
+        } else {
+            self.width
+        }
+    }
 }
 
-impl WordBuilder {
-    pub(crate) fn generate_vector_from_layout_nodes(layout_nodes: Vec<LayoutNode>) -> Vec<Word> {
-        let mut words = Vec::new();
+impl<'a, 'b> WordBuilder<'a, 'b> {
+    pub(crate) fn new(
+        fonts_ctx: &'b mut FontsContext,
+        containing_block_width: f32,
+        file_path: &'b Path,
+    ) -> Self {
+        Self {
+            fonts_ctx,
+            containing_block_width,
+            file_path,
+            words: Vec::new(),
+            atomics: Vec::new(),
+            inline_boxes: Vec::new(),
+        }
+    }
+
+    pub(crate) fn generate_vector_from_layout_nodes(
+        mut self,
+        layout_nodes: Vec<LayoutNode<'a>>,
+        container: &StyledNode,
+        unicode_bidi: BidiMode,
+    ) -> InlineContent<'a> {
+        self.split_layout_nodes_into_words(
+            layout_nodes,
+            container,
+            &[],
+            &[],
+            VerticalPosition::default(),
+        );
+
+        let words = take(&mut self.words);
+
+        // Collapsible spaces are removed at the start of the text, after other collapsible
+        // spaces or preserved line breaks, and before line breaks and the end of the text,
+        // whatever inline boxes start or end between them.
+        let mut collapsed: Vec<Word> = Vec::with_capacity(words.len());
+
+        for word in words {
+            let last = collapsed.iter().rposition(|last| last.boundary.is_none());
+
+            if word.is_collapsible_space()
+                && last.is_none_or(|last| {
+                    collapsed[last].is_collapsible_space() || collapsed[last].is_forced_break()
+                })
+            {
+                continue;
+            }
+
+            if let Some(last) = last {
+                if word.is_forced_break() && collapsed[last].is_collapsible_space() {
+                    collapsed.remove(last);
+                }
+            }
+
+            collapsed.push(word);
+        }
+
+        if let Some(last) = collapsed.iter().rposition(|last| last.boundary.is_none()) {
+            if collapsed[last].is_collapsible_space() {
+                collapsed.remove(last);
+            }
+        }
+
+        let (words, paragraph_level) = WordBuilder::resolve_bidi_levels(
+            collapsed,
+            container.direction().actual_value(),
+            unicode_bidi,
+        );
+        let mut words = WordBuilder::split_at_wrap_opportunities(words);
+        let fonts_ctx = &mut *self.fonts_ctx;
+        let mut space_widths: HashMap<(FontKey, u32, u32), f32> = HashMap::new();
+
+        for word in &mut words {
+            if let Some(boundary) = word.boundary {
+                let inline_box = &self.inline_boxes[*word.inline_boxes.last().unwrap()];
+
+                word.width = inline_box.edges_width(boundary, word.bidi_level);
+                continue;
+            }
+
+            if word.atomic.is_some() {
+                continue;
+            }
+
+            let key = FontKey::new(&word.font_family, word.font_weight, word.font_style);
+            let (size, spacing, rtl) = (
+                word.font_size,
+                word.letter_spacing,
+                word.bidi_level.is_rtl(),
+            );
+
+            // White space is not drawn, only taking room on the line.
+            if word.is_forced_break() {
+                word.width = 0.;
+            } else if word.is_tab() || word.is_space() {
+                let space_width = *space_widths
+                    .entry((key.clone(), size.to_bits(), spacing.to_bits()))
+                    .or_insert_with(|| {
+                        GlyphRun::shape(fonts_ctx, &key, size, " ", spacing, rtl).width
+                    })
+                    + word.word_spacing;
 
-        for node in layout_nodes {
-            let BoxType::Inline(Inline {
-                node: styled_node, ..
-            }) = node.box_type
-            else {
-                panic!("Expected inline node");
+                word.width = if word.is_tab() {
+                    word.tab_size.actual_value(space_width)
+                } else {
+                    space_width
+                };
+            } else {
+                word.glyph_run = GlyphRun::shape(fonts_ctx, &key, size, &word.text, spacing, rtl);
+                word.width = word.glyph_run.width;
+            }
+
+            if word.soft_hyphen {
+                word.hyphen = GlyphRun::shape(fonts_ctx, &key, size, "-", spacing, rtl);
+            }
+        }
+
+        InlineContent {
+            words,
+            atomics: self.atomics,
+            inline_boxes: self.inline_boxes,
+            paragraph_level,
+        }
+    }
+
+    /// Replaced elements and inline-blocks each stand for a word, and the start and end of
+    /// the other elements are words of their own.
+    fn split_layout_nodes_into_words(
+        &mut self,
+        layout_nodes: Vec<LayoutNode<'a>>,
+        parent: &StyledNode,
+        inline_boxes: &[usize],
+        bidi_embeddings: &[BidiEmbedding],
+        vertical_position: VerticalPosition,
+    ) {
+        for mut node in layout_nodes {
+            let (styled_node, cut_left, cut_right) = match node.box_type {
+                BoxType::Inline(Inline {
+                    node,
+                    cut_left,
+                    cut_right,
+                }) => (node, cut_left, cut_right),
+                BoxType::Block(Block { node, .. }) if node.is_inline_block() => {
+                    (node, false, false)
+                }
+                _ => panic!("Expected inline-level node"),
             };
 
             let node_type = &styled_node.node.node_type;
 
-            if let NodeType::Element(_) = node_type {
-                words.extend(WordBuilder::generate_vector_from_layout_nodes(
+            if styled_node.is_replaced_element() || styled_node.is_inline_block() {
+                if styled_node.is_replaced_element() {
+                    Inline::new(styled_node).compute_layout_replaced(
+                        &mut node,
+                        self.containing_block_width,
+                        self.file_path,
+                    );
+                } else {
+                    node.compute_layout_inline_block(
+                        self.containing_block_width,
+                        self.file_path,
+                        self.fonts_ctx,
+                    );
+                }
+
+                // Inline-blocks sit on the baseline of their last line, and the others on their
+                // bottom margin edge.
+                let margin_box = node.box_dimensions.margin_box();
+                let ascent = node
+                    .last_baseline()
+                    .map_or(margin_box.height, |baseline| baseline - margin_box.y);
+                let vertical_position = self.vertical_position(
+                    styled_node,
+                    parent,
+                    (ascent, margin_box.height - ascent),
+                    vertical_position,
+                );
+                let mut word = self.word(
+                    styled_node,
+                    inline_boxes,
+                    bidi_embeddings,
+                    vertical_position,
+                );
+
+                word.text = OBJECT_REPLACEMENT.to_string();
+                word.width = margin_box.width;
+                word.ascent = ascent;
+                word.descent = margin_box.height - ascent;
+                word.atomic = Some(self.atomics.len());
+
+                self.atomics.push(node);
+                self.words.push(word);
+            } else if let NodeType::Element(_) = node_type {
+                let mut bidi_embeddings = bidi_embeddings.to_vec();
+                let direction = styled_node.direction().actual_value();
+
+                if let Some((opening, closing)) = styled_node
+                    .unicode_bidi()
+                    .actual_value()
+                    .controls(direction)
+                {
+                    bidi_embeddings.push(BidiEmbedding {
+                        id: self.words.len(),
+                        opening,
+                        closing,
+                    });
+                }
+
+                let (ascent, descent, line_height) =
+                    WordBuilder::font_metrics(self.fonts_ctx, styled_node);
+                let extents = FormattingContext::line_extents(ascent, descent, line_height);
+                let vertical_position =
+                    self.vertical_position(styled_node, parent, extents, vertical_position);
+                let mut box_dimensions = BoxDimensions::default();
+
+                Inline::new(styled_node)
+                    .compute_edges(&mut box_dimensions, self.containing_block_width);
+
+                let mut inline_boxes = inline_boxes.to_vec();
+
+                inline_boxes.push(self.inline_boxes.len());
+                self.inline_boxes.push(InlineBox {
+                    node: styled_node,
+                    box_dimensions,
+                    ascent,
+                    descent,
+                    line_height,
+                    vertical_position,
+                    clone: styled_node.box_decoration_break().actual_value()
+                        == BoxDecorationBreakMode::Clone,
+                });
+
+                let mut start = self.word(
+                    styled_node,
+                    &inline_boxes,
+                    &bidi_embeddings,
+                    vertical_position,
+                );
+                let end = Word {
+                    boundary: Some(Boundary::End),
+                    ..start.clone()
+                };
+
+                // The pieces of an inline box broken around blocks have no edges where it is
+                // broken.
+                let (cut_start, cut_end) = match direction {
+                    TextDirection::Ltr => (cut_left, cut_right),
+                    TextDirection::Rtl => (cut_right, cut_left),
+                };
+
+                start.boundary = Some(Boundary::Start);
+
+                if !cut_start {
+                    self.words.push(start);
+                }
+
+                self.split_layout_nodes_into_words(
                     node.children,
-                ));
+                    styled_node,
+                    &inline_boxes,
+                    &bidi_embeddings,
+                    vertical_position,
+                );
+
+                if !cut_end {
+                    self.words.push(end);
+                }
             } else if let NodeType::Text(t) = node_type {
-                let line_height = styled_node.line_height().actual_value();
-                let font_size = styled_node.font_size().actual_value();
-                let color = styled_node.color().actual_value();
-                let font_weight = styled_node.font_weight().actual_value();
+                let text_transform = styled_node.text_transform();
+                let collapse = styled_node.white_space_collapse().actual_value();
                 let text = t.get();
+                let template = self.word(
+                    styled_node,
+                    inline_boxes,
+                    bidi_embeddings,
+                    vertical_position,
+                );
+                let new_word = |text: String| Word {
+                    text,
+                    ..template.clone()
+                };
+                let words = &mut self.words;
 
                 let mut word = String::new();
 
                 for c in text.chars() {
-                    if c.is_whitespace() {
+                    if WordBuilder::is_white_space(c) {
                         if !word.is_empty() {
-                            words.push(Word {
-                                text: word.clone(),
-                                width: 0.0,
-                                line_height,
-                                font_size,
-                                font_weight,
-                                color: color.clone(),
-                            });
+                            let at_word_start = WordBuilder::is_at_word_start(words);
 
+                            words.push(new_word(text_transform.apply(&word, at_word_start)));
                             word.clear();
                         }
 
-                        words.push(Word {
-                            text: c.to_string(),
-                            width: 0.0,
-                            line_height,
-                            font_size,
-                            font_weight,
-                            color: color.clone(),
-                        });
+                        // Line breaks and tabs are only kept when `white-space` preserves
+                        // them, and become spaces otherwise.
+                        let white_space = match (c, collapse) {
+                            ('\n', CollapseMode::Collapse) => " ",
+                            ('\n', _) => "\n",
+                            ('\t', CollapseMode::Preserve | CollapseMode::BreakSpaces) => "\t",
+                            _ => " ",
+                        };
+
+                        words.push(new_word(white_space.to_string()));
                     } else {
                         word.push(c);
                     }
                 }
 
                 if !word.is_empty() {
-                    words.push(Word {
-                        text: word,
-                        width: 0.0,
-                        line_height,
-                        font_size,
-                        font_weight,
-                        color: color.clone(),
-                    });
+                    let at_word_start = WordBuilder::is_at_word_start(words);
+
+                    words.push(new_word(text_transform.apply(&word, at_word_start)));
                 }
             }
         }
+    }
 
-        let mut i = 0;
+    fn word(
+        &mut self,
+        styled_node: &StyledNode,
+        inline_boxes: &[usize],
+        bidi_embeddings: &[BidiEmbedding],
+        vertical_position: VerticalPosition,
+    ) -> Word {
+        let (ascent, descent, line_height) = WordBuilder::font_metrics(self.fonts_ctx, styled_node);
 
-        while i < words.len() {
-            if words[i].text.trim().is_empty() {
-                let mut j = i + 1;
+        Word {
+            text: String::new(),
+            glyph_run: GlyphRun::default(),
+            width: 0.0,
+            line_height,
+            ascent,
+            descent,
+            vertical_position,
+            atomic: None,
+            font_family: styled_node.font_family().actual_value().to_vec(),
+            font_size: styled_node.font_size().actual_value(),
+            font_weight: styled_node.font_weight().actual_value(),
+            font_style: styled_node.font_style().actual_value(),
+            color: styled_node.color().actual_value(),
+            text_shadow: styled_node.text_shadow().actual_value().to_vec(),
+            text_decorations: styled_node.text_decorations.clone(),
+            letter_spacing: styled_node.letter_spacing().actual_value(),
+            word_spacing: styled_node.word_spacing().actual_value(),
+            collapse: styled_node.white_space_collapse().actual_value(),
+            wrap: styled_node.text_wrap().actual_value(),
+            tab_size: styled_node.tab_size().clone(),
+            word_break: styled_node.word_break().actual_value(),
+            overflow_wrap: styled_node.overflow_wrap().actual_value(),
+            hyphens: styled_node.hyphens().actual_value(),
+            wrap_after: WrapOpportunity::None,
+            joins_next: false,
+            soft_hyphen: false,
+            hyphen: GlyphRun::default(),
+            bidi_embeddings: bidi_embeddings.to_vec(),
+            bidi_level: Level::ltr(),
+            inline_boxes: inline_boxes.to_vec(),
+            boundary: None,
+        }
+    }
 
-                while j < words.len() && words[j].text.trim().is_empty() {
-                    j += 1;
-                }
+    /// Returns the ascent and descent of the font of the node, and its line height, in pixels.
+    /// A `normal` line height is the one the font asks for.
+    fn font_metrics(fonts_ctx: &mut FontsContext, styled_node: &StyledNode) -> (f32, f32, f32) {
+        let key = WordBuilder::font_key(styled_node);
+        let font_size = styled_node.font_size().actual_value();
+        let (ascent, descent) = fonts_ctx.vertical_metrics(&key, font_size);
+        let normal = fonts_ctx.normal_line_height(&key, font_size);
 
-                words[i].text = " ".to_string();
-                words.drain(i + 1..j);
+        (
+            ascent,
+            descent,
+            styled_node.line_height().actual_value(font_size, normal),
+        )
+    }
+
+    fn font_key(styled_node: &StyledNode) -> FontKey {
+        FontKey::new(
+            styled_node.font_family().actual_value(),
+            styled_node.font_weight().actual_value(),
+            styled_node.font_style().actual_value(),
+        )
+    }
+
+    /// Boxes aligned with the top or bottom of the line start a new alignment for their
+    /// content.
+    fn vertical_position(
+        &mut self,
+        element: &StyledNode,
+        parent: &StyledNode,
+        (above, below): (f32, f32),
+        parent_position: VerticalPosition,
+    ) -> VerticalPosition {
+        let parent_key = WordBuilder::font_key(parent);
+        let parent_size = parent.font_size().actual_value();
+
+        let shift = match element.vertical_align().actual_value() {
+            VerticalAlignMode::Top => {
+                return VerticalPosition {
+                    edge: LineEdge::Top,
+                    id: self.words.len(),
+                    shift: 0.,
+                };
+            }
+            VerticalAlignMode::Bottom => {
+                return VerticalPosition {
+                    edge: LineEdge::Bottom,
+                    id: self.words.len(),
+                    shift: 0.,
+                };
+            }
+            VerticalAlignMode::Baseline => 0.,
+            VerticalAlignMode::Sub => -parent_size / 5.,
+            VerticalAlignMode::Super => parent_size / 3.,
+            VerticalAlignMode::TextTop => {
+                self.fonts_ctx.vertical_metrics(&parent_key, parent_size).0 - above
+            }
+            VerticalAlignMode::TextBottom => {
+                below - self.fonts_ctx.vertical_metrics(&parent_key, parent_size).1
             }
+            VerticalAlignMode::Middle => {
+                self.fonts_ctx.x_height(&parent_key, parent_size) / 2. - (above - below) / 2.
+            }
+            VerticalAlignMode::Length(length) => length,
+            VerticalAlignMode::Percentage(percentage) => {
+                WordBuilder::font_metrics(self.fonts_ctx, element).2 * percentage / 100.
+            }
+        };
+
+        VerticalPosition {
+            shift: parent_position.shift + shift,
+            ..parent_position
+        }
+    }
 
-            i += 1;
+    /// Splits the words where their embedding level changes, and returns the level of the
+    /// first paragraph as well.
+    fn resolve_bidi_levels(
+        words: Vec<Word>,
+        direction: TextDirection,
+        unicode_bidi: BidiMode,
+    ) -> (Vec<Word>, Level) {
+        let mut text = String::new();
+        let mut offsets = Vec::with_capacity(words.len());
+        let mut open: &[BidiEmbedding] = &[];
+
+        if matches!(
+            unicode_bidi,
+            BidiMode::BidiOverride | BidiMode::IsolateOverride
+        ) {
+            text.push_str(BidiMode::BidiOverride.controls(direction).unwrap().0);
         }
 
-        if let Some(first_word) = words.first() {
-            if first_word.text.trim().is_empty() {
-                words.remove(0);
+        for word in &words {
+            let common = open
+                .iter()
+                .zip(&word.bidi_embeddings)
+                .take_while(|(open, embedding)| open.id == embedding.id)
+                .count();
+
+            for embedding in open[common..].iter().rev() {
+                text.push_str(embedding.closing);
             }
-        }
 
-        if let Some(last_word) = words.last() {
-            if last_word.text.trim().is_empty() {
-                words.pop();
+            for embedding in &word.bidi_embeddings[common..] {
+                text.push_str(embedding.opening);
             }
+
+            open = &word.bidi_embeddings;
+            offsets.push(text.len());
+            text.push_str(&word.text);
         }
 
-        let mut font_map = HashMap::new();
+        let base_level = match (unicode_bidi, direction) {
+            (BidiMode::Plaintext, _) => None,
+            (_, TextDirection::Ltr) => Some(Level::ltr()),
+            (_, TextDirection::Rtl) => Some(Level::rtl()),
+        };
 
-        for word in &mut words {
-            let font = font_map.entry(word.font_weight).or_insert_with(|| {
-                SystemSource::new()
-                    .select_best_match(
-                        &[FamilyName::SansSerif],
-                        &Properties {
-                            weight: Weight(word.font_weight as f32),
-                            ..Default::default()
-                        },
-                    )
-                    .unwrap()
-                    .load()
-                    .unwrap()
+        let bidi_info = BidiInfo::new(&text, base_level);
+        let paragraph_level = bidi_info
+            .paragraphs
+            .first()
+            .map_or(Level::ltr(), |paragraph| paragraph.level);
+
+        let levels = &bidi_info.levels;
+        let mut pieces = Vec::with_capacity(words.len());
+
+        for (word, offset) in words.into_iter().zip(offsets) {
+            // Boundaries have no text, taking the level of the words next to them.
+            if word.boundary.is_some() {
+                pieces.push(word);
+                continue;
+            }
+
+            let mut start = 0;
+
+            for (index, _) in word.text.char_indices().skip(1) {
+                if levels[offset + index] != levels[offset + start] {
+                    pieces.push(Word {
+                        text: word.text[start..index].to_string(),
+                        bidi_level: levels[offset + start],
+                        joins_next: true,
+                        ..word.clone()
+                    });
+                    start = index;
+                }
+            }
+
+            pieces.push(Word {
+                text: word.text[start..].to_string(),
+                bidi_level: levels[offset + start],
+                ..word
             });
+        }
+
+        for i in 0..pieces.len() {
+            let Some(boundary) = pieces[i].boundary else {
+                continue;
+            };
+
+            let text = |piece: &&Word| piece.boundary.is_none();
+            let next = pieces[i + 1..].iter().find(text);
+            let previous = pieces[..i].iter().rev().find(text);
+            let neighbor = match boundary {
+                Boundary::Start => next.or(previous),
+                Boundary::End => previous.or(next),
+            };
+
+            pieces[i].bidi_level = neighbor.map_or(paragraph_level, |word| word.bidi_level);
+        }
+
+        (pieces, paragraph_level)
+    }
 
-            word.width = WordBuilder::measure_word_width(&word.text, font, word.font_size);
+    /// Soft hyphens are removed from the pieces, only marking the pieces they end.
+    fn split_at_wrap_opportunities(words: Vec<Word>) -> Vec<Word> {
+        let text: String = words.iter().map(|word| word.text.as_str()).collect();
+        let opportunities: HashSet<usize> = linebreaks(&text).map(|(index, _)| index).collect();
+
+        let mut pieces = Vec::with_capacity(words.len());
+        let mut offset = 0;
+
+        for (i, word) in words.iter().enumerate() {
+            let next = words[i + 1..].iter().find(|next| next.boundary.is_none());
+            let wraps_with_next = word.wrap && next.is_none_or(|next| next.wrap);
+            let chars: Vec<(usize, char)> = word.text.char_indices().collect();
+            let mut start = 0;
+
+            for pair in chars.windows(2) {
+                let ((_, before), (index, after)) = (pair[0], pair[1]);
+                let wrap_after = if word.wrap {
+                    word.wrap_opportunity(opportunities.contains(&(offset + index)), before, after)
+                } else {
+                    WrapOpportunity::None
+                };
+
+                if wrap_after != WrapOpportunity::None {
+                    pieces.push(word.piece(&word.text[start..index], wrap_after, true));
+                    start = index;
+                }
+            }
+
+            let end = word.text.len();
+            let wrap_after = match (next, chars.last()) {
+                (Some(next), Some(&(_, before))) if wraps_with_next => {
+                    let after = next.text.chars().next().unwrap_or(' ');
+
+                    word.wrap_opportunity(opportunities.contains(&(offset + end)), before, after)
+                }
+                _ => WrapOpportunity::None,
+            };
+
+            pieces.push(word.piece(&word.text[start..], wrap_after, word.joins_next));
+            offset += end;
         }
 
+        // Lines wrap after the inline boxes ending after a word rather than before them.
+        for i in 1..pieces.len() {
+            if pieces[i].boundary == Some(Boundary::End) {
+                pieces[i].wrap_after = pieces[i - 1].wrap_after;
+                pieces[i - 1].wrap_after = WrapOpportunity::None;
+            }
+        }
+
+        pieces
+    }
+
+    /// Document white space, which unlike other spaces like no-break spaces separates words.
+    fn is_white_space(c: char) -> bool {
+        matches!(c, ' ' | '\t' | '\n' | '\r' | '\u{c}')
+    }
+
+    /// Tells whether the next piece of text begins a word, as a word can be split across
+    /// inline elements. Pieces without letters, like an opening parenthesis, do not count.
+    fn is_at_word_start(words: &[Word]) -> bool {
         words
+            .iter()
+            .rev()
+            .filter(|word| word.boundary.is_none())
+            .take_while(|word| !word.text.trim().is_empty())
+            .all(|word| !word.text.chars().any(char::is_alphanumeric))
     }
 
-    fn measure_word_width(text: &str, font: &Font, size: f32) -> f32 {
-        let mut total_width = 0.0;
+    /// Returns the end of the segment of words starting at `start` that lines cannot wrap
+    /// inside, unless it overflows them.
+    fn segment_end(words: &[Word], start: usize) -> usize {
+        let mut end = start;
 
-        let glyphs: Vec<u32> = text
-            .chars()
-            .filter_map(|c| font.glyph_for_char(c))
-            .collect();
+        while end < words.len() {
+            let word = &words[end];
+
+            end += 1;
+
+            if word.is_forced_break() {
+                while words
+                    .get(end)
+                    .is_some_and(|word| word.boundary == Some(Boundary::End))
+                {
+                    end += 1;
+                }
+
+                break;
+            }
 
-        for glyph in glyphs {
-            if let Ok(advance) = font.advance(glyph) {
-                total_width += advance.x() * (size / font.metrics().units_per_em as f32);
+            if word.wrap_after == WrapOpportunity::Soft {
+                break;
             }
         }
 
-        total_width * 0.9
+        end
     }
 }
@@ -1,44 +1,81 @@
 use std::mem;
 use std::path::Path;
 
+use font_kit::properties::Style;
+
 use crate::css::types::Rgb;
-use crate::layout::box_types::{anonymous::Anonymous, block::Block, line::Line, word::Word};
+use crate::layout::box_types::{
+    anonymous::Anonymous, block::Block, inline::Inline, line::Line, word::Word,
+};
 use crate::layout::formatting_context::FormattingContext;
 use crate::layout::{box_dimensions::BoxDimensions, box_types::BoxType};
+use crate::painter::{fonts_context::FontsContext, shaping::GlyphRun};
+use crate::style::properties::{
+    box_shadow::Shadow, direction::TextDirection, text_decoration::Decoration,
+};
 use crate::style::types::StyledNode;
+use crate::NodeType;
 
 pub(crate) struct LayoutNodeFactory;
 
 impl LayoutNodeFactory {
-    pub(crate) fn line_box<'a>() -> LayoutNode<'a> {
+    pub(crate) fn line_box<'a>(baseline: f32) -> LayoutNode<'a> {
         LayoutNode {
-            box_type: BoxType::Line(Line),
+            box_type: BoxType::Line(Line { baseline }),
             ..Default::default()
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn word_box<'a>(
         text: String,
+        glyph_run: GlyphRun,
+        font_family: Vec<String>,
         font_size: f32,
         line_height: f32,
+        ascent: f32,
         font_weight: u32,
+        font_style: Style,
         color: Rgb,
+        text_shadow: Vec<Shadow>,
+        text_decorations: Vec<Decoration>,
     ) -> LayoutNode<'a> {
         LayoutNode {
             box_type: BoxType::Word(Word {
                 text,
+                glyph_run,
+                font_family,
                 font_size,
                 line_height,
+                ascent,
                 font_weight,
+                font_style,
                 color,
+                text_shadow,
+                text_decorations,
             }),
             ..Default::default()
         }
     }
 
-    pub(crate) fn anonymous_box<'a>() -> LayoutNode<'a> {
+    pub(crate) fn inline_box<'a>(
+        node: &'a StyledNode<'a>,
+        cut_left: bool,
+        cut_right: bool,
+    ) -> LayoutNode<'a> {
         LayoutNode {
-            box_type: BoxType::Anonymous(Anonymous),
+            box_type: BoxType::Inline(Inline {
+                node,
+                cut_left,
+                cut_right,
+            }),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn anonymous_box<'a>(node: &'a StyledNode<'a>) -> LayoutNode<'a> {
+        LayoutNode {
+            box_type: BoxType::Anonymous(Anonymous { node }),
             ..Default::default()
         }
     }
@@ -57,12 +94,134 @@ impl<'a> LayoutNode<'a> {
         containing_block: &BoxDimensions,
         desired_height: Option<f32>,
         file_path: &Path,
+        fonts_ctx: &mut FontsContext,
+    ) {
+        let box_type = mem::replace(&mut self.box_type, BoxType::Temporal);
+        box_type.compute_layout(self, containing_block, desired_height, file_path, fonts_ctx);
+        self.box_type = box_type;
+    }
+
+    pub(crate) fn compute_layout_inline_block(
+        &mut self,
+        containing_block_width: f32,
+        file_path: &Path,
+        fonts_ctx: &mut FontsContext,
     ) {
+        let desired_height = self.compute_desired_height(None);
         let box_type = mem::replace(&mut self.box_type, BoxType::Temporal);
-        box_type.compute_layout(self, containing_block, desired_height, file_path);
+
+        if let BoxType::Block(block) = &box_type {
+            block.compute_layout_inline_block(
+                self,
+                containing_block_width,
+                desired_height,
+                file_path,
+                fonts_ctx,
+            );
+        }
+
         self.box_type = box_type;
     }
 
+    pub(crate) fn translate(&mut self, dx: f32, dy: f32) {
+        self.box_dimensions.content.x += dx;
+        self.box_dimensions.content.y += dy;
+
+        if let BoxType::Line(line) = &mut self.box_type {
+            line.baseline += dy;
+        }
+
+        for child in &mut self.children {
+            child.translate(dx, dy);
+        }
+    }
+
+    pub(crate) fn last_baseline(&self) -> Option<f32> {
+        if let BoxType::Line(line) = &self.box_type {
+            return Some(line.baseline);
+        }
+
+        self.children
+            .iter()
+            .rev()
+            .find_map(LayoutNode::last_baseline)
+    }
+
+    /// Percentage widths behave as auto, and other percentages of the containing block are
+    /// taken as zero.
+    pub(crate) fn intrinsic_outer_widths(
+        self,
+        file_path: &Path,
+        fonts_ctx: &mut FontsContext,
+    ) -> (f32, f32) {
+        let block = match &self.box_type {
+            BoxType::Block(block) => block,
+            &BoxType::Anonymous(Anonymous { node }) => {
+                return FormattingContext::Inline
+                    .intrinsic_widths(self, node, file_path, fonts_ctx);
+            }
+            _ => return (0., 0.),
+        };
+        let node = block.node;
+
+        let edges = node.padding_left().actual_value(0.)
+            + node.padding_right().actual_value(0.)
+            + node
+                .border_left_width()
+                .actual_value(node.border_left_style().actual_value())
+            + node
+                .border_right_width()
+                .actual_value(node.border_right_style().actual_value())
+            + node.margin_left().actual_value(0.)
+            + node.margin_right().actual_value(0.);
+
+        let is_width_auto = node.width().is_auto() || node.width().is_percentage();
+        let (min_content, max_content) = if node.is_replaced_element() && is_width_auto {
+            let width = Block::intrinsic_image_dimensions(node, file_path).0;
+
+            (width, width)
+        } else if !is_width_auto {
+            let width = node.width().actual_value(0.);
+
+            (width, width)
+        } else {
+            block.intrinsic_widths(file_path, fonts_ctx)
+        };
+
+        (min_content + edges, max_content + edges)
+    }
+
+    /// Tells whether the top and bottom margins of the box adjoin, the box being empty and
+    /// without borders or padding above or below its content.
+    pub(crate) fn collapses_through(&self) -> bool {
+        let BoxDimensions {
+            content,
+            padding,
+            border,
+            ..
+        } = &self.box_dimensions;
+        let is_empty = content.height == 0.
+            && padding.top == 0.
+            && padding.bottom == 0.
+            && border.top == 0.
+            && border.bottom == 0.;
+
+        match &self.box_type {
+            BoxType::Block(Block { node, .. }) => {
+                is_empty
+                    && !node.is_replaced_element()
+                    && !node.establishes_block_formatting_context()
+            }
+            BoxType::Anonymous(_) => is_empty,
+            _ => false,
+        }
+    }
+
+    /// Inline-blocks have block boxes too.
+    pub(crate) fn is_block_level(&self) -> bool {
+        matches!(&self.box_type, BoxType::Block(Block { node, .. }) if node.is_block_level())
+    }
+
     pub(crate) fn compute_desired_height(&self, parent_desired_height: Option<f32>) -> Option<f32> {
         if let BoxType::Block(Block { node, .. }, ..) = &self.box_type {
             let height = node.height();
@@ -78,7 +237,9 @@ impl<'a> LayoutNode<'a> {
     }
 
     pub(crate) fn is_replaced_element(&self) -> bool {
-        if let BoxType::Block(Block { node, .. }) = self.box_type {
+        if let BoxType::Block(Block { node, .. }) | BoxType::Inline(Inline { node, .. }) =
+            self.box_type
+        {
             node.is_replaced_element()
         } else {
             false
@@ -91,6 +252,7 @@ impl<'a> LayoutNode<'a> {
 
     fn handle_inline_level_child(
         &mut self,
+        parent: &'a StyledNode<'a>,
         child: &'a StyledNode<'a>,
         formatting_context: &FormattingContext,
     ) {
@@ -108,35 +270,109 @@ impl<'a> LayoutNode<'a> {
             return;
         }
 
+        if !child.has_block_level_descendants() {
+            self.push_into_anonymous_box(parent, child.into());
+            return;
+        }
+
+        // The block-level boxes inside the inline box become siblings of the pieces of the
+        // inline box around them, which go in anonymous boxes of their own.
+        for piece in LayoutNode::split_around_blocks(child) {
+            if piece.is_block_level() {
+                self.children.push(piece);
+            } else if !piece.is_only_whitespace() {
+                self.push_into_anonymous_box(parent, piece);
+            }
+        }
+    }
+
+    fn push_into_anonymous_box(&mut self, parent: &'a StyledNode<'a>, child: LayoutNode<'a>) {
         let last_child = self.children.last_mut();
 
         if let Some(last_child) = last_child {
             let last_child_is_anonymous = matches!(&last_child.box_type, BoxType::Anonymous(_));
 
             if last_child_is_anonymous {
-                last_child.children.push(child.into());
+                last_child.children.push(child);
                 return;
             }
         }
 
-        let mut anonymous = LayoutNodeFactory::anonymous_box();
+        let mut anonymous = LayoutNodeFactory::anonymous_box(parent);
 
-        anonymous.children.push(child.into());
+        anonymous.children.push(child);
 
         self.children.push(anonymous);
     }
+
+    /// The pieces of the inline box have no edges on the sides where it is broken around a
+    /// block.
+    fn split_around_blocks(node: &'a StyledNode<'a>) -> Vec<LayoutNode<'a>> {
+        let mut runs: Vec<Vec<LayoutNode<'a>>> = vec![Vec::new()];
+        let mut blocks = Vec::new();
+
+        for child in node.children_displayed() {
+            let boxes = if child.is_inline_level() && child.has_block_level_descendants() {
+                LayoutNode::split_around_blocks(child)
+            } else {
+                vec![child.into()]
+            };
+
+            for mut child_box in boxes {
+                if child_box.is_block_level() {
+                    if let BoxType::Block(block) = &mut child_box.box_type {
+                        block.inline_ancestors.push(node);
+                    }
+
+                    blocks.push(child_box);
+                    runs.push(Vec::new());
+                } else {
+                    runs.last_mut().unwrap().push(child_box);
+                }
+            }
+        }
+
+        let rtl = node.direction().actual_value() == TextDirection::Rtl;
+        let last = runs.len() - 1;
+        let mut blocks = blocks.into_iter();
+        let mut pieces = Vec::new();
+
+        for (i, children) in runs.into_iter().enumerate() {
+            let (cut_start, cut_end) = (i > 0, i < last);
+            let (cut_left, cut_right) = if rtl {
+                (cut_end, cut_start)
+            } else {
+                (cut_start, cut_end)
+            };
+            let mut piece = LayoutNodeFactory::inline_box(node, cut_left, cut_right);
+
+            piece.children = children;
+            pieces.push(piece);
+            pieces.extend(blocks.next());
+        }
+
+        pieces
+    }
+
+    /// Tells whether the inline box has nothing but white space in it.
+    fn is_only_whitespace(&self) -> bool {
+        match &self.box_type {
+            BoxType::Inline(Inline { node, .. }) if node.is_replaced_element() => false,
+            BoxType::Inline(Inline { node, .. })
+                if matches!(node.node.node_type, NodeType::Text(_)) =>
+            {
+                node.node.is_only_whitespace()
+            }
+            BoxType::Inline(_) => self.children.iter().all(LayoutNode::is_only_whitespace),
+            _ => false,
+        }
+    }
 }
 
 impl<'a> From<&'a StyledNode<'a>> for LayoutNode<'a> {
     fn from(node: &'a StyledNode) -> LayoutNode<'a> {
         let node_children = node.children_displayed();
-        let has_children = !node_children.is_empty();
         let formatting_context = node.formatting_context();
-        let has_block_formatting_context = formatting_context == FormattingContext::Block;
-
-        if node.is_inline_level() && has_children && has_block_formatting_context {
-            panic!("Inline-level node with block-level children is not supported!");
-        }
 
         let mut ans = LayoutNode::default();
 
@@ -146,7 +382,7 @@ impl<'a> From<&'a StyledNode<'a>> for LayoutNode<'a> {
             }
 
             if child.is_inline_level() {
-                ans.handle_inline_level_child(child, &formatting_context);
+                ans.handle_inline_level_child(node, child, &formatting_context);
             }
         }
 
@@ -1,38 +1,45 @@
 use std::path::Path;
 
 use crate::layout::layout_node::{LayoutNode, LayoutNodeFactory};
+use crate::painter::fonts_context::FontsContext;
 use crate::style::types::StyledNode;
 
 pub(crate) struct LayoutTreeBuilder<'a> {
     dimensions: (usize, usize),
     file_path: &'a Path,
+    fonts_ctx: &'a mut FontsContext,
 }
 
-impl LayoutTreeBuilder<'_> {
-    pub(crate) fn new(dimensions: (usize, usize), file_path: &Path) -> LayoutTreeBuilder {
+impl<'b> LayoutTreeBuilder<'b> {
+    pub(crate) fn new(
+        dimensions: (usize, usize),
+        file_path: &'b Path,
+        fonts_ctx: &'b mut FontsContext,
+    ) -> LayoutTreeBuilder<'b> {
         LayoutTreeBuilder {
             dimensions,
             file_path,
+            fonts_ctx,
         }
     }
 
     pub(crate) fn build<'a>(&mut self, root: &'a StyledNode) -> LayoutNode<'a> {
-        let mut icb = self.build_icb(root.into());
+        let mut icb = self.build_icb(root);
 
         self.compute_boxes(&mut icb);
 
         icb
     }
 
-    fn build_icb<'a>(&self, root: LayoutNode<'a>) -> LayoutNode<'a> {
-        let mut icb = LayoutNodeFactory::anonymous_box();
+    fn build_icb<'a>(&self, root: &'a StyledNode<'a>) -> LayoutNode<'a> {
+        let mut icb = LayoutNodeFactory::anonymous_box(root);
 
-        icb.children.push(root);
+        icb.children.push(root.into());
 
         icb
     }
 
-    fn compute_boxes(&self, icb: &mut LayoutNode) {
+    fn compute_boxes(&mut self, icb: &mut LayoutNode) {
         let (window_width, window_height) = self.window_size();
 
         icb.box_dimensions.content.width = window_width;
@@ -42,7 +49,12 @@ impl LayoutTreeBuilder<'_> {
         let parent_desired_height = Some(window_height);
         let child_desired_height = child.compute_desired_height(parent_desired_height);
 
-        child.compute_layout(containing_block, child_desired_height, self.file_path);
+        child.compute_layout(
+            containing_block,
+            child_desired_height,
+            self.file_path,
+            self.fonts_ctx,
+        );
 
         child.box_dimensions.content.height = window_height;
         icb.box_dimensions.content.height = window_height;
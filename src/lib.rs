@@ -16,7 +16,7 @@ pub struct Config<'a> {
 }
 
 impl Config<'_> {
-    pub fn build(args: &[String]) -> Result<Config, &'static str> {
+    pub fn build(args: &[String]) -> Result<Config<'_>, &'static str> {
         if args.len() < 2 {
             return Err("Not enough arguments. A file path is required.");
         }
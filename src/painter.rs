@@ -4,9 +4,13 @@ use crate::painter::window::Window;
 
 use crate::style::types::StyledNode;
 
+mod blur;
 mod command_list;
 mod commands;
-mod fonts_context;
+mod corner_radii;
+pub(crate) mod fonts_context;
+mod gradient;
+pub(crate) mod shaping;
 mod window;
 
 pub fn paint(root: &StyledNode, file_path: &Path) {
@@ -0,0 +1,160 @@
+use raqote::{DrawOptions, DrawTarget, Image, Path, Point, Source, Transform};
+
+/// Fills the path with a Gaussian blur of the given standard deviation. `bounds` is the
+/// `(x, y, width, height)` rectangle enclosing the path.
+pub(crate) fn fill_blurred(
+    dt: &mut DrawTarget,
+    path: &Path,
+    bounds: (f32, f32, f32, f32),
+    source: &Source,
+    sigma: f32,
+) {
+    if sigma <= 0. {
+        dt.fill(path, source, &DrawOptions::new());
+        return;
+    }
+
+    let (x, y, width, height) = bounds;
+    let extent = blur_extent(sigma);
+
+    draw_blurred(
+        dt,
+        (
+            x - extent,
+            y - extent,
+            width + 2. * extent,
+            height + 2. * extent,
+        ),
+        sigma,
+        |layer, origin| {
+            layer.set_transform(&Transform::translation(-origin.x, -origin.y));
+            layer.fill(path, source, &DrawOptions::new());
+        },
+    );
+}
+
+/// Returns how far a Gaussian blur of the given standard deviation visibly spreads a shape.
+pub(crate) fn blur_extent(sigma: f32) -> f32 {
+    (sigma * 3.).ceil()
+}
+
+/// Renders `draw` onto an offscreen layer covering the `(x, y, width, height)` area, blurs
+/// the layer with a Gaussian of the given standard deviation and composites it onto `dt`.
+///
+/// `draw` receives the position of the layer on `dt`, which it has to subtract from the
+/// coordinates it paints at. A translation transform would do for paths, but raqote does not
+/// apply it when bounding glyphs.
+pub(crate) fn draw_blurred(
+    dt: &mut DrawTarget,
+    area: (f32, f32, f32, f32),
+    sigma: f32,
+    draw: impl FnOnce(&mut DrawTarget, Point),
+) {
+    let (x, y, width, height) = area;
+
+    // Nothing outside the page is visible, so the layer does not need to cover it, except for
+    // the pixels that still bleed into the page once blurred.
+    let margin = blur_extent(sigma);
+    let left = x.max(-margin).floor();
+    let top = y.max(-margin).floor();
+    let right = (x + width).min(dt.width() as f32 + margin).ceil();
+    let bottom = (y + height).min(dt.height() as f32 + margin).ceil();
+
+    if right <= left || bottom <= top {
+        return;
+    }
+
+    let (layer_width, layer_height) = ((right - left) as i32, (bottom - top) as i32);
+    let mut layer = DrawTarget::new(layer_width, layer_height);
+
+    draw(&mut layer, Point::new(left, top));
+
+    box_blur(
+        layer.get_data_mut(),
+        layer_width as usize,
+        layer_height as usize,
+        box_radius(sigma),
+    );
+
+    let image = Image {
+        width: layer_width,
+        height: layer_height,
+        data: layer.get_data(),
+    };
+
+    dt.draw_image_at(left, top, &image, &DrawOptions::new());
+}
+
+/// Returns the radius of the box blur that, applied three times, approximates a Gaussian blur
+/// of the given standard deviation.
+fn box_radius(sigma: f32) -> usize {
+    let size = (sigma * 3. * (2. * std::f32::consts::PI).sqrt() / 4. + 0.5).floor();
+
+    (size / 2.).max(0.) as usize
+}
+
+/// Blurs premultiplied ARGB pixels with three horizontal and vertical box blur passes.
+fn box_blur(data: &mut [u32], width: usize, height: usize, radius: usize) {
+    if radius == 0 {
+        return;
+    }
+
+    let mut buffer = vec![0; data.len()];
+
+    for _ in 0..3 {
+        blur_lines(data, &mut buffer, height, width, radius, |line, i| {
+            line * width + i
+        });
+        blur_lines(&buffer, data, width, height, radius, |line, i| {
+            i * width + line
+        });
+    }
+}
+
+/// Averages every pixel of `src` with its `radius` neighbours along its line into `dst`.
+/// `index` maps a line and a position within it to an index in the pixel buffers.
+fn blur_lines(
+    src: &[u32],
+    dst: &mut [u32],
+    lines: usize,
+    length: usize,
+    radius: usize,
+    index: impl Fn(usize, usize) -> usize,
+) {
+    let window = (2 * radius + 1) as u32;
+
+    for line in 0..lines {
+        let mut sums = [0_u32; 4];
+
+        for i in 0..radius.min(length) {
+            accumulate(&mut sums, src[index(line, i)], true);
+        }
+
+        for i in 0..length {
+            if i + radius < length {
+                accumulate(&mut sums, src[index(line, i + radius)], true);
+            }
+
+            if i > radius {
+                accumulate(&mut sums, src[index(line, i - radius - 1)], false);
+            }
+
+            dst[index(line, i)] = sums.iter().enumerate().fold(0, |pixel, (channel, sum)| {
+                pixel | ((sum / window) << (channel * 8))
+            });
+        }
+    }
+}
+
+/// Adds the channels of the pixel to the running sums, or removes them when `add` is false.
+fn accumulate(sums: &mut [u32; 4], pixel: u32, add: bool) {
+    for (channel, sum) in sums.iter_mut().enumerate() {
+        let value = (pixel >> (channel * 8)) & 0xff;
+
+        if add {
+            *sum += value;
+        } else {
+            *sum -= value;
+        }
+    }
+}
@@ -2,19 +2,34 @@ use std::path::Path;
 
 use crate::{
     layout::{
+        box_dimensions::{BoxDimensions, Rectangle},
         box_types::BoxType,
         box_types::{block::Block, inline::Inline, word::Word},
         layout_node::LayoutNode,
     },
+    style::properties::{
+        background_image::Image, background_origin::BoxArea, background_repeat::RepeatStyle,
+        text_decoration::Decoration, text_decoration_line::DecorationLine,
+    },
     NodeType,
 };
 
 use super::{
     commands::{
-        draw_border::DrawBorder, draw_image::DrawImage, draw_rectangle::DrawRectangle,
-        draw_text::DrawText, Command,
+        draw_background_image::{BackgroundPaint, DrawBackgroundImage},
+        draw_border::{BorderSide, DrawBorder},
+        draw_box_shadow::DrawBoxShadow,
+        draw_image::DrawImage,
+        draw_rectangle::DrawRectangle,
+        draw_text::DrawText,
+        draw_text_decoration::DrawTextDecoration,
+        draw_text_shadow::DrawTextShadow,
+        pop_layer::PopLayer,
+        push_layer::PushLayer,
+        Command,
     },
-    fonts_context::FontsContext,
+    corner_radii::CornerRadii,
+    fonts_context::{FontKey, FontsContext},
 };
 
 pub(crate) struct CommandList {
@@ -47,7 +62,20 @@ impl CommandList {
         fonts_ctx: &mut FontsContext,
         file_path: &Path,
     ) {
-        self.build_commands_for_background(node);
+        let opacity = self.opacity(node);
+
+        // A fully transparent subtree paints nothing.
+        if opacity == 0. {
+            return;
+        }
+
+        if opacity < 1. {
+            self.commands.push(Box::new(PushLayer::new(opacity)));
+        }
+
+        self.build_commands_for_box_shadows(node, false);
+        self.build_commands_for_background(node, file_path);
+        self.build_commands_for_box_shadows(node, true);
         self.build_commands_for_border(node);
 
         if let BoxType::Word { .. } = node.box_type {
@@ -59,6 +87,28 @@ impl CommandList {
                 self.build_commands(child, fonts_ctx, file_path);
             }
         }
+
+        if opacity < 1. {
+            self.commands.push(Box::new(PopLayer));
+        }
+    }
+
+    fn opacity(&self, node: &LayoutNode) -> f32 {
+        match &node.box_type {
+            BoxType::Block(Block {
+                node: styled_node,
+                inline_ancestors,
+                ..
+            }) => inline_ancestors
+                .iter()
+                .fold(styled_node.opacity().actual_value(), |opacity, ancestor| {
+                    opacity * ancestor.opacity().actual_value()
+                }),
+            BoxType::Inline(Inline {
+                node: styled_node, ..
+            }) => styled_node.opacity().actual_value(),
+            _ => 1.,
+        }
     }
 
     fn build_commands_for_border(&mut self, node: &LayoutNode) {
@@ -68,53 +118,205 @@ impl CommandList {
             },
             ..,
         )
-        | BoxType::Inline(Inline { node: styled_node }) = node.box_type
+        | BoxType::Inline(Inline {
+            node: styled_node, ..
+        }) = node.box_type
         {
             let color = styled_node.color().actual_value();
-            let border_color = styled_node.border_color().actual_value(&color);
+            let border = &node.box_dimensions.border;
             let border_box = node.box_dimensions.border_box();
-            let border_width = styled_node
-                .border_width()
-                .actual_value(styled_node.border_style().actual_value());
 
             self.commands.push(Box::new(DrawBorder::new(
                 border_box.x,
                 border_box.y,
                 border_box.width,
                 border_box.height,
-                border_width,
-                border_color.clone(),
+                [
+                    BorderSide::new(
+                        border.top,
+                        styled_node.border_top_style().actual_value().into(),
+                        styled_node.border_top_color().actual_value(&color),
+                    ),
+                    BorderSide::new(
+                        border.right,
+                        styled_node.border_right_style().actual_value().into(),
+                        styled_node.border_right_color().actual_value(&color),
+                    ),
+                    BorderSide::new(
+                        border.bottom,
+                        styled_node.border_bottom_style().actual_value().into(),
+                        styled_node.border_bottom_color().actual_value(&color),
+                    ),
+                    BorderSide::new(
+                        border.left,
+                        styled_node.border_left_style().actual_value().into(),
+                        styled_node.border_left_color().actual_value(&color),
+                    ),
+                ],
+                self.corner_radii(node),
             )));
         }
     }
 
-    fn build_commands_for_background(&mut self, node: &LayoutNode) {
-        if let BoxType::Block(
-            Block {
-                node: styled_node, ..
+    /// Builds the outer shadows, painted below the background, or the inset ones, painted
+    /// between the background and the border. The first shadow in the list is on top.
+    fn build_commands_for_box_shadows(&mut self, node: &LayoutNode, inset: bool) {
+        let (BoxType::Block(Block {
+            node: styled_node, ..
+        })
+        | BoxType::Inline(Inline {
+            node: styled_node, ..
+        })) = node.box_type
+        else {
+            return;
+        };
+
+        let color = styled_node.color().actual_value();
+        let (rect, radii) = self.box_area(
+            node,
+            if inset {
+                BoxArea::Padding
+            } else {
+                BoxArea::Border
             },
-            ..,
-        )
-        | BoxType::Inline(Inline { node: styled_node }) = node.box_type
-        {
-            let background_color = styled_node.background_color().actual_value();
-            let padding_box = node.box_dimensions.padding_box();
-
-            self.commands.push(Box::new(DrawRectangle::new(
-                padding_box.x,
-                padding_box.y,
-                padding_box.width,
-                padding_box.height,
-                background_color.clone(),
+        );
+
+        for shadow in styled_node.box_shadow().actual_value().iter().rev() {
+            if shadow.inset != inset {
+                continue;
+            }
+
+            self.commands.push(Box::new(DrawBoxShadow::new(
+                rect.x,
+                rect.y,
+                rect.width,
+                rect.height,
+                radii.clone(),
+                shadow.clone(),
+                shadow.color(&color),
             )));
         }
     }
 
+    fn build_commands_for_background(&mut self, node: &LayoutNode, document_path: &Path) {
+        let (BoxType::Block(Block {
+            node: styled_node, ..
+        })
+        | BoxType::Inline(Inline {
+            node: styled_node, ..
+        })) = node.box_type
+        else {
+            return;
+        };
+
+        let background_color = styled_node.background_color().actual_value();
+        let (clip, clip_radii) = self.box_area(node, styled_node.background_clip().actual_value());
+
+        self.commands.push(Box::new(DrawRectangle::new(
+            clip.x,
+            clip.y,
+            clip.width,
+            clip.height,
+            background_color.clone(),
+            clip_radii.clone(),
+        )));
+
+        let Some(image) = styled_node.background_image().actual_value() else {
+            return;
+        };
+
+        let (paint, intrinsic_size) = match image {
+            Image::Url(url) => {
+                let Some(folder) = document_path.parent() else {
+                    return;
+                };
+
+                let path = folder.join(url);
+
+                let Ok((width, height)) = image::image_dimensions(&path) else {
+                    return;
+                };
+
+                (
+                    BackgroundPaint::File(path),
+                    Some((width as f32, height as f32)),
+                )
+            }
+            Image::Gradient(gradient) => (BackgroundPaint::Gradient(gradient.clone()), None),
+        };
+
+        let (area, _) = self.box_area(node, styled_node.background_origin().actual_value());
+        let (mut width, mut height) =
+            styled_node
+                .background_size()
+                .actual_value(area.width, area.height, intrinsic_size);
+        let (repeat_x, repeat_y) = styled_node.background_repeat().actual_value();
+
+        // `round` rescales the image so that a whole number of copies fills the area.
+        if repeat_x == RepeatStyle::Round && width > 0. {
+            width = area.width / (area.width / width).round().max(1.);
+        }
+
+        if repeat_y == RepeatStyle::Round && height > 0. {
+            height = area.height / (area.height / height).round().max(1.);
+        }
+
+        let (offset_x, offset_y) =
+            styled_node
+                .background_position()
+                .actual_value(area.width, area.height, width, height);
+
+        let (x, step_x) = CommandList::tile_axis(repeat_x, area.x, area.width, width, offset_x);
+        let (y, step_y) = CommandList::tile_axis(repeat_y, area.y, area.height, height, offset_y);
+
+        self.commands.push(Box::new(DrawBackgroundImage::new(
+            paint,
+            clip,
+            clip_radii,
+            Rectangle {
+                x,
+                y,
+                width,
+                height,
+            },
+            (step_x, step_y),
+        )));
+    }
+
+    /// Returns where the tile of a background image starts along an axis of the positioning
+    /// area, and the distance between consecutive tiles if the image repeats along it.
+    fn tile_axis(
+        repeat: RepeatStyle,
+        area_start: f32,
+        area_length: f32,
+        size: f32,
+        offset: f32,
+    ) -> (f32, Option<f32>) {
+        match repeat {
+            RepeatStyle::Repeat | RepeatStyle::Round => (area_start + offset, Some(size)),
+            RepeatStyle::NoRepeat => (area_start + offset, None),
+            RepeatStyle::Space => {
+                let count = (area_length / size).floor();
+
+                // With fewer than two copies there is no space to distribute.
+                if count < 2. {
+                    return (area_start + offset, None);
+                }
+
+                let gap = (area_length - count * size) / (count - 1.);
+
+                (area_start, Some(size + gap))
+            }
+        }
+    }
+
     fn build_commands_for_image(&mut self, node: &LayoutNode, document_path: &Path) {
         let (BoxType::Block(Block {
             node: styled_node, ..
         })
-        | BoxType::Inline(Inline { node: styled_node })) = node.box_type
+        | BoxType::Inline(Inline {
+            node: styled_node, ..
+        })) = node.box_type
         else {
             return;
         };
@@ -132,6 +334,7 @@ impl CommandList {
         };
 
         let path = folder.join(src);
+        let (_, radii) = self.box_area(node, BoxArea::Content);
 
         self.commands.push(Box::new(DrawImage::new(
             node.box_dimensions.content.x,
@@ -139,30 +342,158 @@ impl CommandList {
             node.box_dimensions.content.width,
             node.box_dimensions.content.height,
             path,
+            radii,
         )));
     }
 
+    /// Returns the rectangle of one of the boxes of the node, and the radii of its curve.
+    fn box_area(&self, node: &LayoutNode, area: BoxArea) -> (Rectangle, CornerRadii) {
+        let BoxDimensions {
+            border, padding, ..
+        } = &node.box_dimensions;
+        let radii = self.corner_radii(node);
+
+        match area {
+            BoxArea::Border => (node.box_dimensions.border_box(), radii),
+            BoxArea::Padding => (
+                node.box_dimensions.padding_box(),
+                radii.shrink(border.top, border.right, border.bottom, border.left),
+            ),
+            BoxArea::Content => (
+                node.box_dimensions.content.clone(),
+                radii.shrink(
+                    border.top + padding.top,
+                    border.right + padding.right,
+                    border.bottom + padding.bottom,
+                    border.left + padding.left,
+                ),
+            ),
+        }
+    }
+
+    /// Fragments of inline boxes have square corners on the sides where they are cut.
+    fn corner_radii(&self, node: &LayoutNode) -> CornerRadii {
+        let (styled_node, cut_left, cut_right) = match node.box_type {
+            BoxType::Block(Block {
+                node: styled_node, ..
+            }) => (styled_node, false, false),
+            BoxType::Inline(Inline {
+                node: styled_node,
+                cut_left,
+                cut_right,
+            }) => (styled_node, cut_left, cut_right),
+            _ => return CornerRadii::default(),
+        };
+
+        let border_box = node.box_dimensions.border_box();
+        let (width, height) = (border_box.width, border_box.height);
+        let mut corners = [
+            styled_node
+                .border_top_left_radius()
+                .actual_value(width, height),
+            styled_node
+                .border_top_right_radius()
+                .actual_value(width, height),
+            styled_node
+                .border_bottom_right_radius()
+                .actual_value(width, height),
+            styled_node
+                .border_bottom_left_radius()
+                .actual_value(width, height),
+        ];
+
+        if cut_left {
+            corners[0] = (0., 0.);
+            corners[3] = (0., 0.);
+        }
+
+        if cut_right {
+            corners[1] = (0., 0.);
+            corners[2] = (0., 0.);
+        }
+
+        CornerRadii::new(corners, width, height)
+    }
+
     fn build_commands_for_text(&mut self, node: &LayoutNode, fonts_ctx: &mut FontsContext) {
         let BoxType::Word(Word {
-            text,
+            glyph_run,
+            font_family,
             font_size,
+            ascent,
             font_weight,
+            font_style,
             color,
+            text_shadow,
+            text_decorations,
             ..
         }) = &node.box_type
         else {
             return;
         };
 
-        fonts_ctx.add_font_if_not_exists(*font_weight);
+        let font_key = FontKey::new(font_family, *font_weight, *font_style);
+        let baseline = node.box_dimensions.content.y + ascent;
+
+        fonts_ctx.add_font_if_not_exists(&font_key);
+
+        for key in &glyph_run.fonts {
+            fonts_ctx.add_font_if_not_exists(key);
+        }
+
+        for shadow in text_shadow.iter().rev() {
+            self.commands.push(Box::new(DrawTextShadow::new(
+                node.box_dimensions.content.x,
+                baseline,
+                node.box_dimensions.content.width,
+                glyph_run.clone(),
+                *font_size,
+                shadow.clone(),
+                shadow.color(color),
+            )));
+        }
+
+        // Underlines and overlines are painted below the text, and line-throughs over it.
+        let (line_throughs, others): (Vec<_>, Vec<_>) = text_decorations
+            .iter()
+            .partition(|decoration| decoration.line == DecorationLine::LineThrough);
+
+        self.build_commands_for_text_decorations(node, baseline, *font_size, &font_key, &others);
 
         self.commands.push(Box::new(DrawText::new(
             node.box_dimensions.content.x,
-            node.box_dimensions.content.y,
-            text.clone(),
+            baseline,
+            glyph_run.clone(),
             *font_size,
-            *font_weight,
             color.clone(),
         )));
+
+        self.build_commands_for_text_decorations(
+            node,
+            baseline,
+            *font_size,
+            &font_key,
+            &line_throughs,
+        );
+    }
+
+    fn build_commands_for_text_decorations(
+        &mut self,
+        node: &LayoutNode,
+        baseline: f32,
+        font_size: f32,
+        font_key: &FontKey,
+        decorations: &[&Decoration],
+    ) {
+        for decoration in decorations {
+            self.commands.push(Box::new(DrawTextDecoration::new(
+                node.box_dimensions.content.x,
+                baseline,
+                node.box_dimensions.content.width,
+                font_size,
+                font_key.clone(),
+                (*decoration).clone(),
+            )));
+        }
     }
 }
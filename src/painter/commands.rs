@@ -2,10 +2,16 @@ use raqote::DrawTarget;
 
 use super::fonts_context::FontsContext;
 
+pub(crate) mod draw_background_image;
 pub(crate) mod draw_border;
+pub(crate) mod draw_box_shadow;
 pub(crate) mod draw_image;
 pub(crate) mod draw_rectangle;
 pub(crate) mod draw_text;
+pub(crate) mod draw_text_decoration;
+pub(crate) mod draw_text_shadow;
+pub(crate) mod pop_layer;
+pub(crate) mod push_layer;
 
 pub(crate) trait Command {
     fn execute(&self, dt: &mut DrawTarget, font_ctx: &mut FontsContext);
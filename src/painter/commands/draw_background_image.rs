@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+
+use image::imageops::FilterType::Triangle;
+use raqote::{DrawOptions, DrawTarget, Image, PathBuilder};
+
+use crate::{
+    layout::box_dimensions::Rectangle,
+    painter::{corner_radii::CornerRadii, fonts_context::FontsContext, gradient::gradient_source},
+    style::gradient::Gradient,
+};
+
+use super::{draw_image::to_argb_data, Command};
+
+/// What a background image tile is painted with.
+pub(crate) enum BackgroundPaint {
+    File(PathBuf),
+    Gradient(Box<Gradient>),
+}
+
+pub(crate) struct DrawBackgroundImage {
+    paint: BackgroundPaint,
+    /// The background painting area, which clips the tiles.
+    clip: Rectangle,
+    clip_radii: CornerRadii,
+    /// The tile every other one is repeated from.
+    tile: Rectangle,
+    /// The distance between the starts of consecutive tiles, horizontally and vertically,
+    /// or `None` when the image does not repeat along that axis.
+    step: (Option<f32>, Option<f32>),
+}
+
+impl DrawBackgroundImage {
+    pub(crate) fn new(
+        paint: BackgroundPaint,
+        clip: Rectangle,
+        clip_radii: CornerRadii,
+        tile: Rectangle,
+        step: (Option<f32>, Option<f32>),
+    ) -> Self {
+        Self {
+            paint,
+            clip,
+            clip_radii,
+            tile,
+            step,
+        }
+    }
+
+    /// Returns where the tiles covering the `[clip_start, clip_end)` range start along an axis.
+    fn tile_starts(start: f32, step: Option<f32>, clip_start: f32, clip_end: f32) -> Vec<f32> {
+        let Some(step) = step.filter(|step| *step > 0.) else {
+            return vec![start];
+        };
+
+        let mut position = start - ((start - clip_start) / step).ceil() * step;
+        let mut starts = Vec::new();
+
+        while position < clip_end {
+            starts.push(position);
+            position += step;
+        }
+
+        starts
+    }
+}
+
+impl Command for DrawBackgroundImage {
+    fn execute(&self, dt: &mut DrawTarget, _font_ctx: &mut FontsContext) {
+        let (width, height) = (self.tile.width.round(), self.tile.height.round());
+
+        if width < 1. || height < 1. {
+            return;
+        }
+
+        let mut pb = PathBuilder::new();
+
+        self.clip_radii.add_rect(
+            &mut pb,
+            self.clip.x,
+            self.clip.y,
+            self.clip.width,
+            self.clip.height,
+        );
+        dt.push_clip(&pb.finish());
+
+        let xs = DrawBackgroundImage::tile_starts(
+            self.tile.x,
+            self.step.0,
+            self.clip.x,
+            self.clip.x + self.clip.width,
+        );
+        let ys = DrawBackgroundImage::tile_starts(
+            self.tile.y,
+            self.step.1,
+            self.clip.y,
+            self.clip.y + self.clip.height,
+        );
+
+        match &self.paint {
+            BackgroundPaint::File(path) => {
+                let Ok(img) = image::open(path) else {
+                    dt.pop_clip();
+                    return;
+                };
+
+                let img = img.resize_exact(width as u32, height as u32, Triangle);
+                let argb_data = to_argb_data(&img);
+
+                let image = Image {
+                    width: width as i32,
+                    height: height as i32,
+                    data: &argb_data,
+                };
+
+                for y in &ys {
+                    for x in &xs {
+                        dt.draw_image_at(*x, *y, &image, &DrawOptions::default());
+                    }
+                }
+            }
+            BackgroundPaint::Gradient(gradient) => {
+                for y in &ys {
+                    for x in &xs {
+                        let tile = Rectangle {
+                            x: *x,
+                            y: *y,
+                            width: self.tile.width,
+                            height: self.tile.height,
+                        };
+
+                        let mut pb = PathBuilder::new();
+
+                        pb.rect(tile.x, tile.y, tile.width, tile.height);
+                        dt.fill(
+                            &pb.finish(),
+                            &gradient_source(gradient, &tile),
+                            &DrawOptions::new(),
+                        );
+                    }
+                }
+            }
+        }
+
+        dt.pop_clip();
+    }
+}
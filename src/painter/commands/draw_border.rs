@@ -1,16 +1,78 @@
-use raqote::{DrawOptions, DrawTarget, PathBuilder, SolidSource, Source};
+use std::f32::consts::PI;
 
-use crate::{css::types::Rgb, painter::fonts_context::FontsContext};
+use raqote::{DrawOptions, DrawTarget, Path, PathBuilder, SolidSource, Source, Winding};
+
+use crate::{
+    css::types::{Rgb, Value},
+    painter::{corner_radii::CornerRadii, fonts_context::FontsContext},
+};
 
 use super::Command;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum LineStyle {
+    Solid,
+    Dashed,
+    Dotted,
+    Double,
+    Groove,
+    Ridge,
+    Inset,
+    Outset,
+}
+
+impl From<&Value> for LineStyle {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "dashed" => LineStyle::Dashed,
+                "dotted" => LineStyle::Dotted,
+                "double" => LineStyle::Double,
+                "groove" => LineStyle::Groove,
+                "ridge" => LineStyle::Ridge,
+                "inset" => LineStyle::Inset,
+                "outset" => LineStyle::Outset,
+                _ => LineStyle::Solid,
+            },
+            _ => LineStyle::Solid,
+        }
+    }
+}
+
+pub(crate) struct BorderSide {
+    width: f32,
+    style: LineStyle,
+    color: Rgb,
+}
+
+impl BorderSide {
+    pub(crate) fn new(width: f32, style: LineStyle, color: Rgb) -> Self {
+        Self {
+            width,
+            style,
+            color,
+        }
+    }
+}
+
+type Polygon = [(f32, f32); 4];
+
+/// A filled part of a side, between two fractions of its width.
+struct Band {
+    side: usize,
+    from: f32,
+    to: f32,
+    color: Rgb,
+}
+
 pub(crate) struct DrawBorder {
     x: f32,
     y: f32,
     width: f32,
     height: f32,
-    border_width: f32,
-    color: Rgb,
+    /// The border sides in top, right, bottom, left order.
+    sides: [BorderSide; 4],
+    radii: CornerRadii,
 }
 
 impl DrawBorder {
@@ -19,48 +81,303 @@ impl DrawBorder {
         y: f32,
         width: f32,
         height: f32,
-        border_width: f32,
-        color: Rgb,
+        sides: [BorderSide; 4],
+        radii: CornerRadii,
     ) -> Self {
         Self {
             x,
             y,
             width,
             height,
-            border_width,
+            sides,
+            radii,
+        }
+    }
+
+    /// Returns the corner radii of the border box shrunk by the given fraction of each width.
+    fn inset_radii(&self, fraction: f32) -> CornerRadii {
+        let [top, right, bottom, left] = self.sides.each_ref().map(|side| side.width * fraction);
+
+        self.radii.shrink(top, right, bottom, left)
+    }
+
+    /// Returns the area between the rounded edges at two fractions of the border widths.
+    fn ring(&self, from: f32, to: f32) -> Path {
+        let mut pb = PathBuilder::new();
+
+        for fraction in [from, to] {
+            let (left, top, right, bottom) = self.inset_edges(fraction);
+
+            self.inset_radii(fraction)
+                .add_rect(&mut pb, left, top, right - left, bottom - top);
+        }
+
+        let mut path = pb.finish();
+        path.winding = Winding::EvenOdd;
+
+        path
+    }
+
+    /// Returns the edges (left, top, right, bottom) of the border box shrunk by the given
+    /// fraction of each border width. A fraction of 0 is the outer edge and 1 the inner edge.
+    fn inset_edges(&self, fraction: f32) -> (f32, f32, f32, f32) {
+        let [top, right, bottom, left] = self.sides.each_ref().map(|side| side.width * fraction);
+
+        (
+            self.x + left,
+            self.y + top,
+            self.x + self.width - right,
+            self.y + self.height - bottom,
+        )
+    }
+
+    /// Returns the trapezoid covered by the band of a side between two fractions of its width,
+    /// so that adjacent sides meet at the diagonal joining the outer and inner corners. With
+    /// rounded corners the inner points move to the centers of the inner curves, and the ring
+    /// between the rounded edges clips the result.
+    fn side_polygon(&self, side: usize, from: f32, to: f32) -> Polygon {
+        let (outer_left, outer_top, outer_right, outer_bottom) = self.inset_edges(from);
+        let (inner_left, inner_top, inner_right, inner_bottom) = self.inset_edges(to);
+        let [top_left, top_right, bottom_right, bottom_left] = self.inset_radii(to).corners();
+
+        let inner_top_left = (inner_left + top_left.0, inner_top + top_left.1);
+        let inner_top_right = (inner_right - top_right.0, inner_top + top_right.1);
+        let inner_bottom_right = (inner_right - bottom_right.0, inner_bottom - bottom_right.1);
+        let inner_bottom_left = (inner_left + bottom_left.0, inner_bottom - bottom_left.1);
+
+        match side {
+            0 => [
+                (outer_left, outer_top),
+                (outer_right, outer_top),
+                inner_top_right,
+                inner_top_left,
+            ],
+            1 => [
+                (outer_right, outer_top),
+                (outer_right, outer_bottom),
+                inner_bottom_right,
+                inner_top_right,
+            ],
+            2 => [
+                (outer_right, outer_bottom),
+                (outer_left, outer_bottom),
+                inner_bottom_left,
+                inner_bottom_right,
+            ],
+            _ => [
+                (outer_left, outer_bottom),
+                (outer_left, outer_top),
+                inner_top_left,
+                inner_bottom_left,
+            ],
+        }
+    }
+
+    /// Splits a side into the filled bands its style is made of, with their colors.
+    fn side_bands(&self, side: usize) -> Vec<Band> {
+        let BorderSide { style, color, .. } = &self.sides[side];
+        let is_top_left = side == 0 || side == 3;
+        let (dark, light) = (shade(color, 2. / 3.), shade(color, 4. / 3.));
+        let (top_left, bottom_right) = if is_top_left {
+            (dark.clone(), light.clone())
+        } else {
+            (light.clone(), dark.clone())
+        };
+
+        let band = |from: f32, to: f32, color: Rgb| Band {
+            side,
+            from,
+            to,
             color,
+        };
+
+        match style {
+            LineStyle::Solid => vec![band(0., 1., color.clone())],
+            LineStyle::Double => vec![
+                band(0., 1. / 3., color.clone()),
+                band(2. / 3., 1., color.clone()),
+            ],
+            LineStyle::Inset => vec![band(0., 1., top_left)],
+            LineStyle::Outset => vec![band(0., 1., bottom_right)],
+            LineStyle::Groove => vec![band(0., 0.5, top_left), band(0.5, 1., bottom_right)],
+            LineStyle::Ridge => vec![band(0., 0.5, bottom_right), band(0.5, 1., top_left)],
+            LineStyle::Dashed | LineStyle::Dotted => Vec::new(),
+        }
+    }
+
+    fn fill_bands(&self, dt: &mut DrawTarget, bands: &[Band]) {
+        let is_rounded = !self.radii.is_zero();
+        let mut painted = vec![false; bands.len()];
+
+        // Bands sharing a color and position are filled as a single path to avoid seams at
+        // the corners
+        for i in 0..bands.len() {
+            if painted[i] {
+                continue;
+            }
+
+            let Band {
+                from, to, color, ..
+            } = &bands[i];
+            let mut pb = PathBuilder::new();
+
+            for j in i..bands.len() {
+                let band = &bands[j];
+
+                if painted[j] || band.from != *from || band.to != *to || &band.color != color {
+                    continue;
+                }
+
+                add_polygon(&mut pb, &self.side_polygon(band.side, band.from, band.to));
+                painted[j] = true;
+            }
+
+            if is_rounded {
+                dt.push_clip(&self.ring(*from, *to));
+            }
+
+            dt.fill(&pb.finish(), &solid_source(color), &DrawOptions::new());
+
+            if is_rounded {
+                dt.pop_clip();
+            }
+        }
+    }
+
+    /// Draws a dashed or dotted side, clipped to its trapezoid so the pattern is cut along the
+    /// corner diagonals like solid sides are.
+    fn draw_pattern(&self, dt: &mut DrawTarget, side: usize) {
+        let BorderSide {
+            width,
+            style,
+            color,
+        } = &self.sides[side];
+        let (outer_left, outer_top, outer_right, outer_bottom) = self.inset_edges(0.);
+        let is_horizontal = side == 0 || side == 2;
+        let length = if is_horizontal {
+            outer_right - outer_left
+        } else {
+            outer_bottom - outer_top
+        };
+
+        // Start of the side along its length and position of the band across it
+        let (start, band) = match side {
+            0 => (outer_left, outer_top),
+            1 => (outer_top, outer_right - width),
+            2 => (outer_left, outer_bottom - width),
+            _ => (outer_top, outer_left),
+        };
+
+        let (segment, gap) = if *style == LineStyle::Dotted {
+            (*width, *width)
+        } else {
+            (*width * 3., *width * 3.)
+        };
+
+        // Segments always start and end at the corners, the gaps absorb the remaining space
+        let count = ((length + gap) / (segment + gap)).round().max(1.);
+        let gap = if count > 1. {
+            (length - count * segment) / (count - 1.)
+        } else {
+            0.
+        };
+
+        let is_rounded = !self.radii.is_zero();
+
+        if is_rounded {
+            dt.push_clip(&self.ring(0., 1.));
+        }
+
+        let mut clip = PathBuilder::new();
+        add_polygon(&mut clip, &self.side_polygon(side, 0., 1.));
+        dt.push_clip(&clip.finish());
+
+        let mut pb = PathBuilder::new();
+
+        for i in 0..count as usize {
+            let offset = start + i as f32 * (segment + gap);
+
+            match (style, is_horizontal) {
+                (LineStyle::Dotted, true) => {
+                    add_circle(&mut pb, offset + width / 2., band + width / 2., width / 2.)
+                }
+                (LineStyle::Dotted, false) => {
+                    add_circle(&mut pb, band + width / 2., offset + width / 2., width / 2.)
+                }
+                (_, true) => pb.rect(offset, band, segment, *width),
+                (_, false) => pb.rect(band, offset, *width, segment),
+            }
+        }
+
+        dt.fill(&pb.finish(), &solid_source(color), &DrawOptions::new());
+        dt.pop_clip();
+
+        if is_rounded {
+            dt.pop_clip();
         }
     }
 }
 
 impl Command for DrawBorder {
     fn execute(&self, dt: &mut DrawTarget, _font_ctx: &mut FontsContext) {
-        let mut pb = PathBuilder::new();
+        let mut bands = Vec::new();
 
-        let source = Source::Solid(SolidSource {
-            r: self.color.r,
-            g: self.color.g,
-            b: self.color.b,
-            a: (self.color.a * 256.0).floor() as u8,
-        });
-
-        pb.rect(self.x, self.y, self.width, self.border_width);
-        pb.rect(
-            self.x,
-            self.y + self.height - self.border_width,
-            self.width,
-            self.border_width,
-        );
-        pb.rect(self.x, self.y, self.border_width, self.height);
-        pb.rect(
-            self.x + self.width - self.border_width,
-            self.y,
-            self.border_width,
-            self.height,
-        );
-
-        let path = pb.finish();
-
-        dt.fill(&path, &source, &DrawOptions::new());
+        for (i, side) in self.sides.iter().enumerate() {
+            if side.width <= 0. {
+                continue;
+            }
+
+            match side.style {
+                LineStyle::Dashed | LineStyle::Dotted => self.draw_pattern(dt, i),
+                _ => bands.extend(self.side_bands(i)),
+            }
+        }
+
+        self.fill_bands(dt, &bands);
     }
 }
+
+fn add_polygon(pb: &mut PathBuilder, polygon: &Polygon) {
+    let [first, rest @ ..] = polygon;
+
+    pb.move_to(first.0, first.1);
+
+    for point in rest {
+        pb.line_to(point.0, point.1);
+    }
+
+    pb.close();
+}
+
+fn add_circle(pb: &mut PathBuilder, x: f32, y: f32, radius: f32) {
+    pb.move_to(x + radius, y);
+    pb.arc(x, y, radius, 0., 2. * PI);
+    pb.close();
+}
+
+/// Darkens (factor below 1) or lightens (factor above 1) a color for the 3D border styles.
+fn shade(color: &Rgb, factor: f32) -> Rgb {
+    let channel = |c: u8| {
+        if factor < 1. {
+            (c as f32 * factor) as u8
+        } else {
+            (c as f32 + (255. - c as f32) * (factor - 1.)) as u8
+        }
+    };
+
+    Rgb::new(
+        channel(color.r),
+        channel(color.g),
+        channel(color.b),
+        color.a,
+    )
+}
+
+fn solid_source(color: &Rgb) -> Source<'static> {
+    Source::Solid(SolidSource::from_unpremultiplied_argb(
+        (color.a * 255.).round() as u8,
+        color.r,
+        color.g,
+        color.b,
+    ))
+}
@@ -0,0 +1,142 @@
+use raqote::{DrawTarget, PathBuilder, SolidSource, Source, Winding};
+
+use crate::{
+    css::types::Rgb,
+    painter::{blur, corner_radii::CornerRadii, fonts_context::FontsContext},
+    style::properties::box_shadow::Shadow,
+};
+
+use super::Command;
+
+pub(crate) struct DrawBoxShadow {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    /// The radii of the box casting the shadow: the border box for outer shadows and the
+    /// padding box for inset ones.
+    radii: CornerRadii,
+    shadow: Shadow,
+    color: Rgb,
+}
+
+impl DrawBoxShadow {
+    pub(crate) fn new(
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        radii: CornerRadii,
+        shadow: Shadow,
+        color: Rgb,
+    ) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            radii,
+            shadow,
+            color,
+        }
+    }
+
+    /// Paints the shadow outside the box, offset and grown by the spread distance.
+    fn draw_outer(&self, dt: &mut DrawTarget, source: &Source) {
+        let spread = self.shadow.spread;
+        let x = self.x + self.shadow.offset_x - spread;
+        let y = self.y + self.shadow.offset_y - spread;
+        let width = self.width + 2. * spread;
+        let height = self.height + 2. * spread;
+
+        if width <= 0. || height <= 0. {
+            return;
+        }
+
+        // The shadow is only visible outside the border box.
+        let mut pb = PathBuilder::new();
+
+        pb.rect(0., 0., dt.width() as f32, dt.height() as f32);
+        self.radii
+            .add_rect(&mut pb, self.x, self.y, self.width, self.height);
+
+        let mut clip = pb.finish();
+        clip.winding = Winding::EvenOdd;
+
+        let mut pb = PathBuilder::new();
+
+        self.radii
+            .spread(spread)
+            .add_rect(&mut pb, x, y, width, height);
+
+        dt.push_clip(&clip);
+        blur::fill_blurred(
+            dt,
+            &pb.finish(),
+            (x, y, width, height),
+            source,
+            self.shadow.blur / 2.,
+        );
+        dt.pop_clip();
+    }
+
+    /// Paints the shadow inside the box, around a hole offset and shrunk by the spread distance.
+    fn draw_inset(&self, dt: &mut DrawTarget, source: &Source) {
+        let spread = self.shadow.spread;
+        let x = self.x + self.shadow.offset_x + spread;
+        let y = self.y + self.shadow.offset_y + spread;
+        let width = self.width - 2. * spread;
+        let height = self.height - 2. * spread;
+
+        let mut pb = PathBuilder::new();
+
+        self.radii
+            .add_rect(&mut pb, self.x, self.y, self.width, self.height);
+
+        let clip = pb.finish();
+
+        // The shadow is drawn as a frame around the hole that extends past the box by the
+        // blur, so the blurred edges of the box stay fully shaded.
+        let margin = blur::blur_extent(self.shadow.blur / 2.) + 1.;
+        let bounds = (
+            self.x - margin,
+            self.y - margin,
+            self.width + 2. * margin,
+            self.height + 2. * margin,
+        );
+
+        let mut pb = PathBuilder::new();
+
+        pb.rect(bounds.0, bounds.1, bounds.2, bounds.3);
+
+        if width > 0. && height > 0. {
+            self.radii
+                .spread(-spread)
+                .add_rect(&mut pb, x, y, width, height);
+        }
+
+        let mut frame = pb.finish();
+        frame.winding = Winding::EvenOdd;
+
+        dt.push_clip(&clip);
+        blur::fill_blurred(dt, &frame, bounds, source, self.shadow.blur / 2.);
+        dt.pop_clip();
+    }
+}
+
+impl Command for DrawBoxShadow {
+    fn execute(&self, dt: &mut DrawTarget, _font_ctx: &mut FontsContext) {
+        let source = Source::Solid(SolidSource::from_unpremultiplied_argb(
+            (self.color.a * 255.).round() as u8,
+            self.color.r,
+            self.color.g,
+            self.color.b,
+        ));
+
+        if self.shadow.inset {
+            self.draw_inset(dt, &source);
+        } else {
+            self.draw_outer(dt, &source);
+        }
+    }
+}
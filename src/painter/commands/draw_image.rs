@@ -1,9 +1,9 @@
 use std::path::PathBuf;
 
-use image::{imageops::FilterType::Nearest, GenericImageView};
-use raqote::{DrawOptions, DrawTarget, Image};
+use image::{imageops::FilterType::Nearest, DynamicImage, GenericImageView};
+use raqote::{DrawOptions, DrawTarget, Image, PathBuilder};
 
-use crate::painter::fonts_context::FontsContext;
+use crate::painter::{corner_radii::CornerRadii, fonts_context::FontsContext};
 
 use super::Command;
 
@@ -13,16 +13,26 @@ pub(crate) struct DrawImage {
     width: f32,
     height: f32,
     path: PathBuf,
+    /// The radii of the content box curve the image is clipped to.
+    radii: CornerRadii,
 }
 
 impl DrawImage {
-    pub(crate) fn new(x: f32, y: f32, width: f32, height: f32, path: PathBuf) -> Self {
+    pub(crate) fn new(
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        path: PathBuf,
+        radii: CornerRadii,
+    ) -> Self {
         Self {
             x,
             y,
             width,
             height,
             path,
+            radii,
         }
     }
 }
@@ -35,14 +45,7 @@ impl Command for DrawImage {
 
         let img = img.resize_exact(self.width as u32, self.height as u32, Nearest);
         let (img_width, img_height) = img.dimensions();
-        let mut argb_data = vec![0; (img_width * img_height) as usize];
-
-        for (x, y, pixel) in img.to_rgba8().enumerate_pixels() {
-            let idx = (y * img_width + x) as usize;
-            let [r, g, b, a] = pixel.0;
-            argb_data[idx] =
-                ((a as u32) << 24) | ((r as u32) << 16) | ((g as u32) << 8) | (b as u32);
-        }
+        let argb_data = to_argb_data(&img);
 
         let image = Image {
             width: img_width as i32,
@@ -50,6 +53,33 @@ impl Command for DrawImage {
             data: &argb_data,
         };
 
+        let is_rounded = !self.radii.is_zero();
+
+        if is_rounded {
+            let mut pb = PathBuilder::new();
+
+            self.radii
+                .add_rect(&mut pb, self.x, self.y, self.width, self.height);
+            dt.push_clip(&pb.finish());
+        }
+
         dt.draw_image_at(self.x, self.y, &image, &DrawOptions::default());
+
+        if is_rounded {
+            dt.pop_clip();
+        }
     }
 }
+
+/// Converts the image to the premultiplied ARGB pixels raqote draws.
+pub(super) fn to_argb_data(img: &DynamicImage) -> Vec<u32> {
+    img.to_rgba8()
+        .pixels()
+        .map(|pixel| {
+            let [r, g, b, a] = pixel.0;
+            let premultiply = |channel: u8| (channel as u32 * a as u32 + 127) / 255;
+
+            ((a as u32) << 24) | (premultiply(r) << 16) | (premultiply(g) << 8) | premultiply(b)
+        })
+        .collect()
+}
@@ -1,6 +1,9 @@
 use raqote::{DrawOptions, DrawTarget, PathBuilder, SolidSource, Source};
 
-use crate::{css::types::Rgb, painter::fonts_context::FontsContext};
+use crate::{
+    css::types::Rgb,
+    painter::{corner_radii::CornerRadii, fonts_context::FontsContext},
+};
 
 use super::Command;
 
@@ -10,16 +13,25 @@ pub(crate) struct DrawRectangle {
     width: f32,
     height: f32,
     color: Rgb,
+    radii: CornerRadii,
 }
 
 impl DrawRectangle {
-    pub(crate) fn new(x: f32, y: f32, width: f32, height: f32, color: Rgb) -> Self {
+    pub(crate) fn new(
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        color: Rgb,
+        radii: CornerRadii,
+    ) -> Self {
         Self {
             x,
             y,
             width,
             height,
             color,
+            radii,
         }
     }
 }
@@ -28,14 +40,15 @@ impl Command for DrawRectangle {
     fn execute(&self, dt: &mut DrawTarget, _font_ctx: &mut FontsContext) {
         let mut pb = PathBuilder::new();
 
-        let source = Source::Solid(SolidSource {
-            r: self.color.r,
-            g: self.color.g,
-            b: self.color.b,
-            a: (self.color.a * 256.0).floor() as u8,
-        });
+        let source = Source::Solid(SolidSource::from_unpremultiplied_argb(
+            (self.color.a * 255.).round() as u8,
+            self.color.r,
+            self.color.g,
+            self.color.b,
+        ));
 
-        pb.rect(self.x, self.y, self.width, self.height);
+        self.radii
+            .add_rect(&mut pb, self.x, self.y, self.width, self.height);
 
         let path = pb.finish();
 
@@ -1,33 +1,27 @@
 use raqote::{DrawOptions, DrawTarget, Point, SolidSource, Source};
 
-use crate::{css::types::Rgb, painter::fonts_context::FontsContext};
+use crate::{
+    css::types::Rgb,
+    painter::{fonts_context::FontsContext, shaping::GlyphRun},
+};
 
 use super::Command;
 
 pub(crate) struct DrawText {
     x: f32,
     y: f32,
-    text: String,
+    glyph_run: GlyphRun,
     font_size: f32,
-    font_weight: u32,
     color: Rgb,
 }
 
 impl DrawText {
-    pub(crate) fn new(
-        x: f32,
-        y: f32,
-        text: String,
-        font_size: f32,
-        font_weight: u32,
-        color: Rgb,
-    ) -> Self {
+    pub(crate) fn new(x: f32, y: f32, glyph_run: GlyphRun, font_size: f32, color: Rgb) -> Self {
         Self {
             x,
             y,
-            text,
+            glyph_run,
             font_size,
-            font_weight,
             color,
         }
     }
@@ -42,14 +36,41 @@ impl Command for DrawText {
             a: 255,
         });
 
-        let font = font_ctx.get_font(self.font_weight);
-
-        dt.draw_text(
-            font,
+        draw_glyph_run(
+            dt,
+            font_ctx,
             self.font_size,
-            &self.text,
+            &self.glyph_run,
             Point::new(self.x, self.y),
             &source,
+        );
+    }
+}
+
+/// Draws the glyphs of the run with its baseline starting at `origin`, each sequence of
+/// glyphs of the same font at once.
+pub(super) fn draw_glyph_run(
+    dt: &mut DrawTarget,
+    font_ctx: &FontsContext,
+    font_size: f32,
+    glyph_run: &GlyphRun,
+    origin: Point,
+    source: &Source,
+) {
+    for glyphs in glyph_run.glyphs.chunk_by(|a, b| a.font == b.font) {
+        let font = font_ctx.get_font(&glyph_run.fonts[glyphs[0].font]);
+
+        let (ids, positions): (Vec<u32>, Vec<Point>) = glyphs
+            .iter()
+            .map(|glyph| (glyph.id, Point::new(origin.x + glyph.x, origin.y + glyph.y)))
+            .unzip();
+
+        dt.draw_glyphs(
+            font,
+            font_size,
+            &ids,
+            &positions,
+            source,
             &DrawOptions::new(),
         );
     }
@@ -0,0 +1,182 @@
+use std::f32::consts::PI;
+
+use raqote::{DrawOptions, DrawTarget, PathBuilder, SolidSource, Source, StrokeStyle};
+
+use crate::{
+    painter::fonts_context::{FontKey, FontsContext},
+    style::properties::{
+        text_decoration::Decoration, text_decoration_line::DecorationLine,
+        text_decoration_style::DecorationStyle,
+    },
+};
+
+use super::Command;
+
+pub(crate) struct DrawTextDecoration {
+    x: f32,
+    y: f32,
+    width: f32,
+    font_size: f32,
+    font_key: FontKey,
+    decoration: Decoration,
+}
+
+impl DrawTextDecoration {
+    pub(crate) fn new(
+        x: f32,
+        y: f32,
+        width: f32,
+        font_size: f32,
+        font_key: FontKey,
+        decoration: Decoration,
+    ) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            font_size,
+            font_key,
+            decoration,
+        }
+    }
+
+    /// Adds dots, dashes or waves along the line. Their pattern is aligned to the page rather
+    /// than to the word, so it continues across the words of a line.
+    fn add_pattern(&self, pb: &mut PathBuilder, center: f32, thickness: f32) {
+        let (period, segment) = match self.decoration.style {
+            DecorationStyle::Dotted => (thickness * 2., thickness),
+            DecorationStyle::Dashed => (thickness * 6., thickness * 3.),
+            _ => ((thickness * 6.).max(6.), 0.),
+        };
+
+        let end = self.x + self.width;
+        let mut x = (self.x / period).floor() * period;
+
+        if self.decoration.style == DecorationStyle::Wavy {
+            let amplitude = period / 4.;
+
+            pb.move_to(x, center);
+
+            while x < end {
+                pb.quad_to(
+                    x + period / 4.,
+                    center - amplitude * 2.,
+                    x + period / 2.,
+                    center,
+                );
+                pb.quad_to(
+                    x + period * 3. / 4.,
+                    center + amplitude * 2.,
+                    x + period,
+                    center,
+                );
+                x += period;
+            }
+
+            return;
+        }
+
+        while x < end {
+            if self.decoration.style == DecorationStyle::Dotted {
+                let radius = segment / 2.;
+
+                pb.move_to(x + radius * 2., center);
+                pb.arc(x + radius, center, radius, 0., 2. * PI);
+                pb.close();
+            } else {
+                pb.rect(x, center - thickness / 2., segment, thickness);
+            }
+
+            x += period;
+        }
+    }
+}
+
+impl Command for DrawTextDecoration {
+    fn execute(&self, dt: &mut DrawTarget, font_ctx: &mut FontsContext) {
+        let metrics = font_ctx.get_font(&self.font_key).metrics();
+        let scale = self.font_size / metrics.units_per_em as f32;
+        let thickness = self
+            .decoration
+            .thickness
+            .unwrap_or(metrics.underline_thickness * scale)
+            .round()
+            .max(1.);
+
+        // Where the middle of the line goes, and to which side of it the second line of a
+        // double decoration goes, away from the text.
+        let (center, outwards) = match self.decoration.line {
+            DecorationLine::Underline => (self.y - metrics.underline_position * scale, 1.),
+            DecorationLine::Overline => (self.y - metrics.ascent * scale + thickness / 2., -1.),
+            DecorationLine::LineThrough => {
+                let x_height = if metrics.x_height > 0. {
+                    metrics.x_height
+                } else {
+                    metrics.ascent / 2.
+                };
+
+                (self.y - x_height * scale / 2., 0.)
+            }
+        };
+
+        let color = &self.decoration.color;
+        let source = Source::Solid(SolidSource::from_unpremultiplied_argb(
+            (color.a * 255.).round() as u8,
+            color.r,
+            color.g,
+            color.b,
+        ));
+
+        let mut pb = PathBuilder::new();
+
+        // Straight lines are snapped to whole pixels to stay crisp.
+        let top = |center: f32| (center - thickness / 2.).round();
+
+        match self.decoration.style {
+            DecorationStyle::Solid => {
+                pb.rect(self.x, top(center), self.width, thickness);
+            }
+            DecorationStyle::Double => {
+                let distance = thickness + thickness.max(2.);
+
+                // A line-through is split around its middle.
+                let (first, second) = if outwards == 0. {
+                    (center - distance / 2., center + distance / 2.)
+                } else {
+                    (center, center + outwards * distance)
+                };
+
+                pb.rect(self.x, top(first), self.width, thickness);
+                pb.rect(self.x, top(second), self.width, thickness);
+            }
+            DecorationStyle::Dotted | DecorationStyle::Dashed | DecorationStyle::Wavy => {
+                let mut clip = PathBuilder::new();
+
+                clip.rect(self.x, center - thickness * 4., self.width, thickness * 8.);
+                dt.push_clip(&clip.finish());
+
+                self.add_pattern(&mut pb, center, thickness);
+
+                if self.decoration.style == DecorationStyle::Wavy {
+                    dt.stroke(
+                        &pb.finish(),
+                        &source,
+                        &StrokeStyle {
+                            width: thickness,
+                            ..Default::default()
+                        },
+                        &DrawOptions::new(),
+                    );
+                } else {
+                    dt.fill(&pb.finish(), &source, &DrawOptions::new());
+                }
+
+                dt.pop_clip();
+
+                return;
+            }
+        }
+
+        dt.fill(&pb.finish(), &source, &DrawOptions::new());
+    }
+}
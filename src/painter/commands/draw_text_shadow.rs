@@ -0,0 +1,88 @@
+use raqote::{DrawTarget, Point, SolidSource, Source};
+
+use crate::{
+    css::types::Rgb,
+    painter::{blur, fonts_context::FontsContext, shaping::GlyphRun},
+    style::properties::box_shadow::Shadow,
+};
+
+use super::{draw_text::draw_glyph_run, Command};
+
+pub(crate) struct DrawTextShadow {
+    x: f32,
+    y: f32,
+    width: f32,
+    glyph_run: GlyphRun,
+    font_size: f32,
+    shadow: Shadow,
+    color: Rgb,
+}
+
+impl DrawTextShadow {
+    pub(crate) fn new(
+        x: f32,
+        y: f32,
+        width: f32,
+        glyph_run: GlyphRun,
+        font_size: f32,
+        shadow: Shadow,
+        color: Rgb,
+    ) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            glyph_run,
+            font_size,
+            shadow,
+            color,
+        }
+    }
+}
+
+impl Command for DrawTextShadow {
+    fn execute(&self, dt: &mut DrawTarget, font_ctx: &mut FontsContext) {
+        let source = Source::Solid(SolidSource::from_unpremultiplied_argb(
+            (self.color.a * 255.).round() as u8,
+            self.color.r,
+            self.color.g,
+            self.color.b,
+        ));
+
+        let origin = Point::new(self.x + self.shadow.offset_x, self.y + self.shadow.offset_y);
+        let sigma = self.shadow.blur / 2.;
+
+        if sigma <= 0. {
+            draw_glyph_run(
+                dt,
+                font_ctx,
+                self.font_size,
+                &self.glyph_run,
+                origin,
+                &source,
+            );
+            return;
+        }
+
+        // The glyphs sit on the baseline at `y`, rising at most a font size above it and
+        // descending about half of it below.
+        let extent = blur::blur_extent(sigma);
+        let area = (
+            origin.x - extent,
+            origin.y - self.font_size - extent,
+            self.width + 2. * extent,
+            self.font_size * 1.5 + 2. * extent,
+        );
+
+        blur::draw_blurred(dt, area, sigma, |layer, layer_origin| {
+            draw_glyph_run(
+                layer,
+                font_ctx,
+                self.font_size,
+                &self.glyph_run,
+                Point::new(origin.x - layer_origin.x, origin.y - layer_origin.y),
+                &source,
+            );
+        });
+    }
+}
@@ -0,0 +1,14 @@
+use raqote::DrawTarget;
+
+use crate::painter::fonts_context::FontsContext;
+
+use super::Command;
+
+/// Composites the layer started by the matching `PushLayer` onto the layer below it.
+pub(crate) struct PopLayer;
+
+impl Command for PopLayer {
+    fn execute(&self, dt: &mut DrawTarget, _font_ctx: &mut FontsContext) {
+        dt.pop_layer();
+    }
+}
@@ -0,0 +1,23 @@
+use raqote::DrawTarget;
+
+use crate::painter::fonts_context::FontsContext;
+
+use super::Command;
+
+/// Starts rendering into an offscreen layer that `PopLayer` composites with the given opacity,
+/// so overlapping descendants are faded as a group.
+pub(crate) struct PushLayer {
+    opacity: f32,
+}
+
+impl PushLayer {
+    pub(crate) fn new(opacity: f32) -> Self {
+        Self { opacity }
+    }
+}
+
+impl Command for PushLayer {
+    fn execute(&self, dt: &mut DrawTarget, _font_ctx: &mut FontsContext) {
+        dt.push_layer(self.opacity);
+    }
+}
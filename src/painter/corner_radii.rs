@@ -0,0 +1,127 @@
+use raqote::PathBuilder;
+
+/// Distance of the Bézier control points used to approximate a quarter of an ellipse.
+const KAPPA: f32 = 0.552_284_8;
+
+/// The horizontal and vertical radii of the corners of a box, in top-left, top-right,
+/// bottom-right and bottom-left order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct CornerRadii {
+    corners: [(f32, f32); 4],
+}
+
+impl CornerRadii {
+    /// Creates the radii for a box of the given size, scaling them down uniformly when the
+    /// curves of two adjacent corners would overlap.
+    pub(crate) fn new(corners: [(f32, f32); 4], width: f32, height: f32) -> Self {
+        let [top_left, top_right, bottom_right, bottom_left] = corners;
+
+        let factor = [
+            width / (top_left.0 + top_right.0),
+            height / (top_right.1 + bottom_right.1),
+            width / (bottom_right.0 + bottom_left.0),
+            height / (bottom_left.1 + top_left.1),
+        ]
+        .into_iter()
+        .filter(|factor| factor.is_finite())
+        .fold(1_f32, f32::min)
+        .max(0.);
+
+        Self {
+            corners: corners.map(|(x, y)| (x * factor, y * factor)),
+        }
+    }
+
+    pub(crate) fn corners(&self) -> [(f32, f32); 4] {
+        self.corners
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.corners.iter().all(|&(x, y)| x <= 0. || y <= 0.)
+    }
+
+    /// Returns the radii of the box inset by the given edge widths, as the inner curve of a
+    /// border or the padding edge.
+    pub(crate) fn shrink(&self, top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        let [top_left, top_right, bottom_right, bottom_left] = self.corners;
+
+        Self {
+            corners: [
+                ((top_left.0 - left).max(0.), (top_left.1 - top).max(0.)),
+                ((top_right.0 - right).max(0.), (top_right.1 - top).max(0.)),
+                (
+                    (bottom_right.0 - right).max(0.),
+                    (bottom_right.1 - bottom).max(0.),
+                ),
+                (
+                    (bottom_left.0 - left).max(0.),
+                    (bottom_left.1 - bottom).max(0.),
+                ),
+            ],
+        }
+    }
+
+    /// Returns the radii of the box grown by the given spread distance, or shrunk when it is
+    /// negative. Square corners stay square.
+    pub(crate) fn spread(&self, amount: f32) -> Self {
+        Self {
+            corners: self.corners.map(|(x, y)| {
+                if x <= 0. || y <= 0. {
+                    (x, y)
+                } else {
+                    ((x + amount).max(0.), (y + amount).max(0.))
+                }
+            }),
+        }
+    }
+
+    /// Adds the rounded rectangle as a closed sub-path of the given path builder.
+    pub(crate) fn add_rect(&self, pb: &mut PathBuilder, x: f32, y: f32, width: f32, height: f32) {
+        if self.is_zero() {
+            pb.rect(x, y, width, height);
+            return;
+        }
+
+        let [top_left, top_right, bottom_right, bottom_left] = self.corners;
+        let (right, bottom) = (x + width, y + height);
+
+        pb.move_to(x + top_left.0, y);
+        pb.line_to(right - top_right.0, y);
+        pb.cubic_to(
+            right - top_right.0 * (1. - KAPPA),
+            y,
+            right,
+            y + top_right.1 * (1. - KAPPA),
+            right,
+            y + top_right.1,
+        );
+        pb.line_to(right, bottom - bottom_right.1);
+        pb.cubic_to(
+            right,
+            bottom - bottom_right.1 * (1. - KAPPA),
+            right - bottom_right.0 * (1. - KAPPA),
+            bottom,
+            right - bottom_right.0,
+            bottom,
+        );
+        pb.line_to(x + bottom_left.0, bottom);
+        pb.cubic_to(
+            x + bottom_left.0 * (1. - KAPPA),
+            bottom,
+            x,
+            bottom - bottom_left.1 * (1. - KAPPA),
+            x,
+            bottom - bottom_left.1,
+        );
+        pb.line_to(x, y + top_left.1);
+        pb.cubic_to(
+            x,
+            y + top_left.1 * (1. - KAPPA),
+            x + top_left.0 * (1. - KAPPA),
+            y,
+            x + top_left.0,
+            y,
+        );
+        pb.close();
+    }
+}
@@ -1,39 +1,181 @@
 use font_kit::{
     family_name::FamilyName,
     font::Font,
-    properties::{Properties, Weight},
+    properties::{Properties, Style, Weight},
     source::SystemSource,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+use crate::style::utils::{families_to_family_names, family_to_family_name};
+
+/// The properties used to select a font face: family list, weight and style.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub(crate) struct FontKey {
+    pub(crate) family: Vec<String>,
+    pub(crate) weight: u32,
+    pub(crate) style: Style,
+}
+
+impl Eq for FontKey {}
+
+impl FontKey {
+    pub(crate) fn new(family: &[String], weight: u32, style: Style) -> Self {
+        Self {
+            family: family.to_vec(),
+            weight,
+            style,
+        }
+    }
+
+    pub(crate) fn load(&self, source: &SystemSource) -> Font {
+        self.try_load(source, &families_to_family_names(&self.family))
+            .unwrap()
+    }
+
+    fn try_load(&self, source: &SystemSource, family_names: &[FamilyName]) -> Option<Font> {
+        source
+            .select_best_match(
+                family_names,
+                &Properties {
+                    weight: Weight(self.weight as f32),
+                    style: self.style,
+                    ..Default::default()
+                },
+            )
+            .ok()?
+            .load()
+            .ok()
+    }
+}
 
 pub struct FontsContext {
-    pub fonts: HashMap<u32, Font>,
+    pub fonts: HashMap<FontKey, Font>,
+    source: SystemSource,
+    /// The fonts found for the characters missing from a font.
+    fallbacks: HashMap<(FontKey, char), FontKey>,
+    /// The fallback candidates found not to have a character.
+    missing_glyphs: HashSet<(FontKey, char)>,
+    /// The fallback candidates that are not installed.
+    unavailable: HashSet<FontKey>,
+    system_families: Option<Vec<String>>,
 }
 
 impl FontsContext {
     pub fn new() -> Self {
         Self {
             fonts: HashMap::new(),
+            source: SystemSource::new(),
+            fallbacks: HashMap::new(),
+            missing_glyphs: HashSet::new(),
+            unavailable: HashSet::new(),
+            system_families: None,
+        }
+    }
+
+    /// Returns the font that draws the character in text of the given font. It is the font
+    /// itself when it has a glyph for the character, or else the first font with one among
+    /// each family of the font and then the families of the system, in the same weight and
+    /// style. Characters no font has are left to the font itself.
+    pub fn font_for_char(&mut self, key: &FontKey, c: char) -> FontKey {
+        if self.add_font_if_not_exists(key).glyph_for_char(c).is_some() {
+            return key.clone();
         }
+
+        if let Some(fallback) = self.fallbacks.get(&(key.clone(), c)) {
+            return fallback.clone();
+        }
+
+        let source = &self.source;
+        let system_families = self
+            .system_families
+            .get_or_insert_with(|| {
+                let mut families = source.all_families().unwrap_or_default();
+                families.sort();
+                families
+            })
+            .clone();
+
+        let fallback = key
+            .family
+            .iter()
+            .chain(&system_families)
+            .map(|family| FontKey::new(std::slice::from_ref(family), key.weight, key.style))
+            .find(|candidate| self.has_glyph(candidate, c))
+            .unwrap_or_else(|| key.clone());
+
+        self.fallbacks.insert((key.clone(), c), fallback.clone());
+
+        fallback
     }
 
-    pub fn add_font_if_not_exists(&mut self, font_weight: u32) -> &Font {
-        self.fonts.entry(font_weight).or_insert_with(|| {
-            SystemSource::new()
-                .select_best_match(
-                    &[FamilyName::SansSerif],
-                    &Properties {
-                        weight: Weight(font_weight as f32),
-                        ..Default::default()
-                    },
-                )
-                .unwrap()
-                .load()
-                .unwrap()
-        })
+    /// Candidates are loaded without the sans-serif fallback, and only kept when they have
+    /// the character.
+    fn has_glyph(&mut self, key: &FontKey, c: char) -> bool {
+        if let Some(font) = self.fonts.get(key) {
+            return font.glyph_for_char(c).is_some();
+        }
+
+        if self.unavailable.contains(key) || self.missing_glyphs.contains(&(key.clone(), c)) {
+            return false;
+        }
+
+        let family_names: Vec<FamilyName> = key
+            .family
+            .iter()
+            .map(|family| family_to_family_name(family))
+            .collect();
+        let Some(font) = key.try_load(&self.source, &family_names) else {
+            self.unavailable.insert(key.clone());
+            return false;
+        };
+
+        if font.glyph_for_char(c).is_none() {
+            self.missing_glyphs.insert((key.clone(), c));
+            return false;
+        }
+
+        self.fonts.insert(key.clone(), font);
+
+        true
+    }
+
+    pub fn add_font_if_not_exists(&mut self, key: &FontKey) -> &Font {
+        let source = &self.source;
+
+        self.fonts
+            .entry(key.clone())
+            .or_insert_with(|| key.load(source))
+    }
+
+    /// Returns the ascent and descent of the font at the given size, both positive.
+    pub fn vertical_metrics(&mut self, key: &FontKey, font_size: f32) -> (f32, f32) {
+        let metrics = self.add_font_if_not_exists(key).metrics();
+        let scale = font_size / metrics.units_per_em as f32;
+
+        (metrics.ascent * scale, -metrics.descent * scale)
+    }
+
+    /// Returns the line height the font asks for at the given size, its ascent and descent
+    /// with the gap between lines.
+    pub fn normal_line_height(&mut self, key: &FontKey, font_size: f32) -> f32 {
+        let metrics = self.add_font_if_not_exists(key).metrics();
+        let scale = font_size / metrics.units_per_em as f32;
+
+        (metrics.ascent - metrics.descent + metrics.line_gap) * scale
+    }
+
+    /// Half the font size when the font does not tell.
+    pub fn x_height(&mut self, key: &FontKey, font_size: f32) -> f32 {
+        let metrics = self.add_font_if_not_exists(key).metrics();
+
+        if metrics.x_height > 0. {
+            metrics.x_height * font_size / metrics.units_per_em as f32
+        } else {
+            font_size / 2.
+        }
     }
 
-    pub fn get_font(&self, font_weight: u32) -> &Font {
-        self.fonts.get(&font_weight).expect("Font not found")
+    pub fn get_font(&self, key: &FontKey) -> &Font {
+        self.fonts.get(key).expect("Font not found")
     }
 }
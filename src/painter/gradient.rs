@@ -0,0 +1,268 @@
+use raqote::{
+    Color, Gradient as StopList, GradientStop, Point, SolidSource, Source, Spread, Transform,
+};
+
+use crate::{
+    css::types::{Rgb, Value},
+    layout::box_dimensions::Rectangle,
+    style::gradient::{
+        ColorStop, Gradient, GradientKind, LinearDirection, RadialShape, RadialSize,
+    },
+};
+
+/// Builds the source that paints the gradient over the given rectangle.
+pub(crate) fn gradient_source(gradient: &Gradient, rect: &Rectangle) -> Source<'static> {
+    match &gradient.kind {
+        GradientKind::Linear(direction) => linear_source(gradient, direction, rect),
+        GradientKind::Radial {
+            shape,
+            size,
+            position,
+        } => radial_source(gradient, *shape, size, position, rect),
+        GradientKind::Conic { from, position } => conic_source(gradient, *from, position, rect),
+    }
+}
+
+fn linear_source(
+    gradient: &Gradient,
+    direction: &LinearDirection,
+    rect: &Rectangle,
+) -> Source<'static> {
+    let (width, height) = (rect.width, rect.height);
+
+    // The direction of a corner is perpendicular to the diagonal between the other two.
+    let (dx, dy) = match direction {
+        LinearDirection::Angle(angle) => (angle.to_radians().sin(), -angle.to_radians().cos()),
+        LinearDirection::Corner(x, y) => {
+            let length = width.hypot(height);
+
+            (x * height / length, y * width / length)
+        }
+    };
+
+    // The gradient line is long enough for the corners to get the colors of its ends.
+    let length = (width * dx).abs() + (height * dy).abs();
+    let start = (
+        rect.x + width / 2. - dx * length / 2.,
+        rect.y + height / 2. - dy * length / 2.,
+    );
+
+    let Some((stops, first, last)) = resolve_stops(gradient, length, false) else {
+        return solid_source(&gradient.stops.last().unwrap().color());
+    };
+
+    Source::new_linear_gradient(
+        stops,
+        Point::new(start.0 + dx * first, start.1 + dy * first),
+        Point::new(start.0 + dx * last, start.1 + dy * last),
+        spread(gradient),
+    )
+}
+
+fn radial_source(
+    gradient: &Gradient,
+    shape: RadialShape,
+    size: &RadialSize,
+    position: &(Value, Value),
+    rect: &Rectangle,
+) -> Source<'static> {
+    let (cx, cy) = resolve_position(position, rect);
+    let (left, right) = (cx - rect.x, rect.x + rect.width - cx);
+    let (top, bottom) = (cy - rect.y, rect.y + rect.height - cy);
+
+    let (closest_x, closest_y) = (left.abs().min(right.abs()), top.abs().min(bottom.abs()));
+    let (farthest_x, farthest_y) = (left.abs().max(right.abs()), top.abs().max(bottom.abs()));
+
+    let (rx, ry) = match (size, shape) {
+        (RadialSize::ClosestSide, RadialShape::Circle) => {
+            let radius = closest_x.min(closest_y);
+            (radius, radius)
+        }
+        (RadialSize::FarthestSide, RadialShape::Circle) => {
+            let radius = farthest_x.max(farthest_y);
+            (radius, radius)
+        }
+        (RadialSize::ClosestCorner, RadialShape::Circle) => {
+            let radius = closest_x.hypot(closest_y);
+            (radius, radius)
+        }
+        (RadialSize::FarthestCorner, RadialShape::Circle) => {
+            let radius = farthest_x.hypot(farthest_y);
+            (radius, radius)
+        }
+        (RadialSize::ClosestSide, RadialShape::Ellipse) => (closest_x, closest_y),
+        (RadialSize::FarthestSide, RadialShape::Ellipse) => (farthest_x, farthest_y),
+        // Ellipses through a corner keep the aspect ratio of the matching side ellipse.
+        (RadialSize::ClosestCorner, RadialShape::Ellipse) => (
+            closest_x * std::f32::consts::SQRT_2,
+            closest_y * std::f32::consts::SQRT_2,
+        ),
+        (RadialSize::FarthestCorner, RadialShape::Ellipse) => (
+            farthest_x * std::f32::consts::SQRT_2,
+            farthest_y * std::f32::consts::SQRT_2,
+        ),
+        (RadialSize::Explicit(x, y), _) => (
+            resolve_length(x, rect.width),
+            resolve_length(y, rect.height),
+        ),
+    };
+
+    let Some((stops, first, last)) = resolve_stops(gradient, rx, false).filter(|_| ry > 0.) else {
+        return solid_source(&gradient.stops.last().unwrap().color());
+    };
+
+    // The stops are laid along the horizontal radius, so the ellipse is squashed into a
+    // circle of that radius around the origin.
+    let transform = Transform::translation(-cx, -cy).then_scale(1., rx / ry);
+
+    Source::TwoCircleRadialGradient(
+        stops,
+        spread(gradient),
+        Point::new(0., 0.),
+        first,
+        Point::new(0., 0.),
+        last,
+        transform,
+    )
+}
+
+fn conic_source(
+    gradient: &Gradient,
+    from: f32,
+    position: &(Value, Value),
+    rect: &Rectangle,
+) -> Source<'static> {
+    let (cx, cy) = resolve_position(position, rect);
+
+    let Some((stops, first, last)) = resolve_stops(gradient, 1., true) else {
+        return solid_source(&gradient.stops.last().unwrap().color());
+    };
+
+    // raqote measures angles clockwise from the right, and offsets the gradient by its start
+    // angle scaled by the span instead of subtracting it, so the start is passed mirrored. A
+    // gradient that covers the whole turn has to wrap around it.
+    let span = (last - first) * 360.;
+    let start = -(from - 90. + first * 360.) / (last - first);
+
+    Source::new_sweep_gradient(
+        stops,
+        Point::new(cx, cy),
+        start,
+        start + span,
+        Spread::Repeat,
+    )
+}
+
+fn spread(gradient: &Gradient) -> Spread {
+    if gradient.repeating {
+        Spread::Repeat
+    } else {
+        Spread::Pad
+    }
+}
+
+/// Resolves the stop positions along a gradient of the given length, turns for conic
+/// gradients. Returns the stops normalized between the first and last positions the source
+/// has to span, or `None` when these are the same.
+fn resolve_stops(gradient: &Gradient, length: f32, is_conic: bool) -> Option<(StopList, f32, f32)> {
+    let mut positions: Vec<Option<f32>> = gradient
+        .stops
+        .iter()
+        .map(|stop| resolve_stop_position(stop, length, is_conic))
+        .collect();
+
+    let count = positions.len();
+
+    positions[0].get_or_insert(0.);
+    positions[count - 1].get_or_insert(length);
+
+    // A stop cannot come before the ones preceding it.
+    let mut max = f32::MIN;
+
+    for position in positions.iter_mut().flatten() {
+        *position = position.max(max);
+        max = *position;
+    }
+
+    // Stops without a position are spread evenly between the ones around them.
+    let mut i = 1;
+
+    while i < count {
+        if positions[i].is_some() {
+            i += 1;
+            continue;
+        }
+
+        let end = (i..count).find(|&j| positions[j].is_some()).unwrap();
+        let (from, to) = (positions[i - 1].unwrap(), positions[end].unwrap());
+
+        for (k, position) in positions.iter_mut().enumerate().take(end).skip(i) {
+            *position = Some(from + (to - from) * (k + 1 - i) as f32 / (end + 1 - i) as f32);
+        }
+
+        i = end;
+    }
+
+    let positions: Vec<f32> = positions.into_iter().flatten().collect();
+
+    // Repeating gradients repeat the span of their stops, while the others have to cover
+    // the whole gradient, padded with the colors of the ends.
+    let (first, last) = if gradient.repeating {
+        (positions[0], positions[count - 1])
+    } else {
+        (positions[0].min(0.), positions[count - 1].max(length))
+    };
+
+    if last - first <= f32::EPSILON {
+        return None;
+    }
+
+    let stops = gradient
+        .stops
+        .iter()
+        .zip(positions)
+        .map(|(stop, position)| {
+            let color = stop.color();
+
+            GradientStop {
+                position: (position - first) / (last - first),
+                color: Color::new((color.a * 255.).round() as u8, color.r, color.g, color.b),
+            }
+        })
+        .collect();
+
+    Some((StopList { stops }, first, last))
+}
+
+fn resolve_stop_position(stop: &ColorStop, length: f32, is_conic: bool) -> Option<f32> {
+    match stop.position.as_ref()? {
+        Value::Percentage(percentage) => Some(length * percentage / 100.),
+        Value::Dimension(angle, _) if is_conic => Some(angle / 360.),
+        Value::Dimension(length, _) => Some(*length),
+        _ => None,
+    }
+}
+
+fn resolve_position(position: &(Value, Value), rect: &Rectangle) -> (f32, f32) {
+    (
+        rect.x + resolve_length(&position.0, rect.width),
+        rect.y + resolve_length(&position.1, rect.height),
+    )
+}
+
+fn resolve_length(value: &Value, reference: f32) -> f32 {
+    match value {
+        Value::Dimension(length, _) => *length,
+        Value::Percentage(percentage) => reference * percentage / 100.,
+        _ => 0.,
+    }
+}
+
+fn solid_source(color: &Rgb) -> Source<'static> {
+    Source::Solid(SolidSource::from_unpremultiplied_argb(
+        (color.a * 255.).round() as u8,
+        color.r,
+        color.g,
+        color.b,
+    ))
+}
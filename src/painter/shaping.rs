@@ -0,0 +1,207 @@
+use font_kit::font::Font;
+use rustybuzz::{
+    ttf_parser::{fonts_in_collection, name_id, Tag},
+    Direction, Face, Feature, UnicodeBuffer,
+};
+use unicode_properties::{GeneralCategory, GeneralCategoryGroup, UnicodeGeneralCategory};
+
+use super::fonts_context::{FontKey, FontsContext};
+
+/// A glyph of a run, positioned on its baseline from the start of the run.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PositionedGlyph {
+    /// The index of the font of the glyph among the fonts of the run.
+    pub(crate) font: usize,
+    pub(crate) id: u32,
+    pub(crate) x: f32,
+    pub(crate) y: f32,
+}
+
+/// Text shaped with a font, with its kerning and ligatures. Words are shaped once during
+/// layout, which measures them with the run, and the painter draws the same glyphs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct GlyphRun {
+    /// The font of the text, followed by the fallback fonts of the characters it lacks.
+    pub(crate) fonts: Vec<FontKey>,
+    pub(crate) glyphs: Vec<PositionedGlyph>,
+    pub(crate) width: f32,
+}
+
+impl GlyphRun {
+    /// Shapes the text with the font at the given size. The text is split into pieces drawn
+    /// by the same font, falling back to other fonts for the characters the font lacks, and
+    /// these are shaped on their own and laid out in visual order.
+    pub(crate) fn shape(
+        fonts_ctx: &mut FontsContext,
+        font_key: &FontKey,
+        font_size: f32,
+        text: &str,
+        letter_spacing: f32,
+        rtl: bool,
+    ) -> Self {
+        let mut starts: Vec<(usize, FontKey)> = Vec::new();
+
+        for (index, c) in text.char_indices() {
+            // Marks and format characters like joiners stay with the character before them.
+            if index > 0 && is_cluster_extension(c) {
+                continue;
+            }
+
+            let key = fonts_ctx.font_for_char(font_key, c);
+
+            if starts.last().is_none_or(|(_, last)| *last != key) {
+                starts.push((index, key));
+            }
+        }
+
+        let mut pieces: Vec<(&FontKey, &str)> = starts
+            .iter()
+            .enumerate()
+            .map(|(i, (start, key))| {
+                let end = starts.get(i + 1).map_or(text.len(), |(end, _)| *end);
+
+                (key, &text[*start..end])
+            })
+            .collect();
+
+        if rtl {
+            pieces.reverse();
+        }
+
+        let mut run = GlyphRun {
+            fonts: vec![font_key.clone()],
+            ..Default::default()
+        };
+
+        for (key, piece) in pieces {
+            let font = fonts_ctx.get_font(key);
+
+            run.extend(&GlyphRun::shape_with_font(
+                font,
+                key.clone(),
+                font_size,
+                piece,
+                letter_spacing,
+                rtl,
+            ));
+        }
+
+        run
+    }
+
+    /// Shapes the text with the font, adding the letter spacing after each cluster. Letter
+    /// spacing disables the optional ligatures, which it would pull apart. Right-to-left text
+    /// has its glyphs reversed and mirrored, still positioned from the left.
+    fn shape_with_font(
+        font: &Font,
+        font_key: FontKey,
+        font_size: f32,
+        text: &str,
+        letter_spacing: f32,
+        rtl: bool,
+    ) -> Self {
+        let Some(data) = font.copy_font_data() else {
+            return Self::default();
+        };
+
+        let Some(face) = Face::from_slice(&data, face_index(font, &data)) else {
+            return Self::default();
+        };
+
+        let scale = font_size / face.units_per_em() as f32;
+        let features: Vec<Feature> = if letter_spacing != 0. {
+            [b"liga", b"clig", b"dlig"]
+                .iter()
+                .map(|tag| Feature::new(Tag::from_bytes(tag), 0, ..))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut buffer = UnicodeBuffer::new();
+        buffer.push_str(text);
+        buffer.set_direction(if rtl {
+            Direction::RightToLeft
+        } else {
+            Direction::LeftToRight
+        });
+
+        let output = rustybuzz::shape(&face, &features, buffer);
+        let infos = output.glyph_infos();
+        let mut glyphs = Vec::with_capacity(infos.len());
+        let mut x = 0.;
+
+        for (i, (info, position)) in infos.iter().zip(output.glyph_positions()).enumerate() {
+            glyphs.push(PositionedGlyph {
+                font: 0,
+                id: info.glyph_id,
+                x: x + position.x_offset as f32 * scale,
+                y: -position.y_offset as f32 * scale,
+            });
+
+            x += position.x_advance as f32 * scale;
+
+            if infos
+                .get(i + 1)
+                .is_none_or(|next| next.cluster != info.cluster)
+            {
+                x += letter_spacing;
+            }
+        }
+
+        Self {
+            fonts: vec![font_key],
+            glyphs,
+            width: x,
+        }
+    }
+
+    /// Appends the glyphs of another run after the ones of this run.
+    pub(crate) fn extend(&mut self, other: &GlyphRun) {
+        let fonts: Vec<usize> = other
+            .fonts
+            .iter()
+            .map(|key| {
+                self.fonts
+                    .iter()
+                    .position(|font| font == key)
+                    .unwrap_or_else(|| {
+                        self.fonts.push(key.clone());
+                        self.fonts.len() - 1
+                    })
+            })
+            .collect();
+
+        self.glyphs
+            .extend(other.glyphs.iter().map(|glyph| PositionedGlyph {
+                font: fonts[glyph.font],
+                x: glyph.x + self.width,
+                ..glyph.clone()
+            }));
+        self.width += other.width;
+    }
+}
+
+/// Tells whether the character belongs to the cluster of the character before it.
+fn is_cluster_extension(c: char) -> bool {
+    c.general_category_group() == GeneralCategoryGroup::Mark
+        || c.general_category() == GeneralCategory::Format
+}
+
+/// Finds the face of the font in its data, which holds every face of a font collection.
+fn face_index(font: &Font, data: &[u8]) -> u32 {
+    let (Some(count), Some(name)) = (fonts_in_collection(data), font.postscript_name()) else {
+        return 0;
+    };
+
+    (0..count)
+        .find(|&index| {
+            Face::from_slice(data, index).is_some_and(|face| {
+                face.names().into_iter().any(|entry| {
+                    entry.name_id == name_id::POST_SCRIPT_NAME
+                        && entry.to_string().is_some_and(|entry| entry == name)
+                })
+            })
+        })
+        .unwrap_or(0)
+}
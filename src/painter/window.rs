@@ -9,6 +9,7 @@ use super::{command_list::CommandList, fonts_context::FontsContext};
 
 pub(crate) struct Window {
     window: MinifbWindow,
+    fonts_ctx: FontsContext,
 }
 
 impl Window {
@@ -25,7 +26,10 @@ impl Window {
         )
         .unwrap();
 
-        Self { window }
+        Self {
+            window,
+            fonts_ctx: FontsContext::new(),
+        }
     }
 
     pub(crate) fn run(&mut self, root: &StyledNode, file_path: &Path) {
@@ -48,15 +52,14 @@ impl Window {
     }
 
     fn render(&mut self, node: &StyledNode, size: (usize, usize), file_path: &Path) -> DrawTarget {
-        let mut font_ctx = FontsContext::new();
         let mut dt = DrawTarget::new(size.0 as i32, size.1 as i32);
-        let root = layout::build_layout_tree(node, file_path, size);
-        let commands = CommandList::new(&root, &mut font_ctx, file_path);
+        let root = layout::build_layout_tree(node, file_path, size, &mut self.fonts_ctx);
+        let commands = CommandList::new(&root, &mut self.fonts_ctx, file_path);
 
         self.clear_canvas(&mut dt);
 
         for command in &commands {
-            command.execute(&mut dt, &mut font_ctx);
+            command.execute(&mut dt, &mut self.fonts_ctx);
         }
 
         dt
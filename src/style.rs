@@ -1,11 +1,16 @@
+use std::cell::OnceCell;
+
+use properties::Property;
 use properties::PropertyRegistry;
 use types::{StyledNode, Styles};
+use unicode_bidi::{get_base_direction, Direction};
 
 use crate::{
     css::types::{Declaration, Stylesheet},
     Node, NodeType,
 };
 
+pub(crate) mod gradient;
 pub(crate) mod properties;
 pub(crate) mod types;
 pub(crate) mod utils;
@@ -34,37 +39,58 @@ pub(crate) fn build_style_tree<'a>(
         &property_registry,
     );
 
-    let children = vec![build_style_node(
+    let mut html = StyledNode {
+        node: html_node,
+        styles,
+        text_decorations: Vec::new(),
+        intrinsic_widths: OnceCell::new(),
+        children: Vec::new(),
+    };
+
+    html.text_decorations = html.established_text_decorations();
+    html.children = vec![build_style_node(
         body_node,
         author_stylesheet,
         user_agent_stylesheet,
-        Some(&styles),
+        &html,
         &property_registry,
     )];
 
-    StyledNode {
-        node: html_node,
-        styles,
-        children,
-    }
+    html
 }
 
 fn build_style_node<'a>(
     node: &'a Node,
     author_stylesheet: &Stylesheet,
     user_agent_stylesheet: &Stylesheet,
-    parent_styles: Option<&Styles>,
+    parent: &StyledNode,
     property_registry: &PropertyRegistry,
 ) -> StyledNode<'a> {
     let styles = find_styles(
         node,
         author_stylesheet,
         user_agent_stylesheet,
-        parent_styles,
+        Some(&parent.styles),
         property_registry,
     );
 
-    let children = node
+    let mut styled_node = StyledNode {
+        node,
+        styles,
+        text_decorations: parent.text_decorations.clone(),
+        intrinsic_widths: OnceCell::new(),
+        children: Vec::new(),
+    };
+
+    // Decorations do not propagate into atomic inline-level boxes.
+    if styled_node.is_inline_block() {
+        styled_node.text_decorations.clear();
+    }
+
+    let established = styled_node.established_text_decorations();
+
+    styled_node.text_decorations.extend(established);
+    styled_node.children = node
         .children
         .iter()
         .map(|child| {
@@ -72,17 +98,13 @@ fn build_style_node<'a>(
                 child,
                 author_stylesheet,
                 user_agent_stylesheet,
-                Some(&styles),
+                &styled_node,
                 property_registry,
             )
         })
         .collect();
 
-    StyledNode {
-        node,
-        styles,
-        children,
-    }
+    styled_node
 }
 
 fn is_tag_node(node: &Node, tag: &str) -> bool {
@@ -106,6 +128,15 @@ fn find_styles(
         styles.apply(&rule.declarations, property_registry);
     }
 
+    // Presentational hints
+    let dir_attribute_declarations = find_dir_attribute_declarations(node);
+
+    styles.apply(&dir_attribute_declarations, property_registry);
+
+    let dimension_attribute_declarations = find_dimension_attribute_declarations(node);
+
+    styles.apply(&dimension_attribute_declarations, property_registry);
+
     // Author rules
     let mut author_rules = author_stylesheet.matching_rules(node);
     author_rules.sort_by_key(|rule| rule.specificity());
@@ -118,6 +149,27 @@ fn find_styles(
 
     styles.apply(&style_attribute_declarations, property_registry);
 
+    // Computed values
+    let parent_font_size = match parent_styles.and_then(|styles| styles.get("font-size")) {
+        Some(Property::FontSize(parent_font_size)) => parent_font_size.actual_value(),
+        _ => 16.,
+    };
+
+    if let Some(Property::FontSize(font_size)) = styles.get("font-size") {
+        styles.add(Property::FontSize(
+            font_size.computed_value(parent_font_size),
+        ));
+    }
+
+    if let Some(Property::LineHeight(line_height)) = styles.get("line-height") {
+        let font_size = match styles.get("font-size") {
+            Some(Property::FontSize(font_size)) => font_size.actual_value(),
+            _ => parent_font_size,
+        };
+
+        styles.add(Property::LineHeight(line_height.computed_value(font_size)));
+    }
+
     // Defaulting values (Inheritance)
     if let Some(parent_styles) = parent_styles {
         for property_name in property_registry.inheritable_properties() {
@@ -141,6 +193,76 @@ fn find_styles(
     styles
 }
 
+/// Maps the `dir` attribute to `direction`, isolating the text of the element like the HTML
+/// user agent stylesheet. The direction of `auto`, which `<bdi>` defaults to, comes from the
+/// first strong character of the text of the element.
+fn find_dir_attribute_declarations(node: &Node) -> Vec<Declaration> {
+    let NodeType::Element(element) = &node.node_type else {
+        return Vec::new();
+    };
+
+    let dir = match element.attributes().get("dir") {
+        Some(dir) => dir.to_ascii_lowercase(),
+        None if element.tag_name() == "bdi" => "auto".to_string(),
+        None => return Vec::new(),
+    };
+
+    let direction = match dir.as_str() {
+        "ltr" | "rtl" => dir.as_str(),
+        "auto" if get_base_direction(text_content(node).as_str()) == Direction::Rtl => "rtl",
+        "auto" => "ltr",
+        _ => return Vec::new(),
+    };
+
+    let unicode_bidi = if element.tag_name() == "bdo" {
+        "isolate-override"
+    } else {
+        "isolate"
+    };
+
+    crate::css::parse_list_of_declarations(&format!(
+        "direction: {direction}; unicode-bidi: {unicode_bidi}"
+    ))
+}
+
+/// Maps the `width` and `height` attributes of images to the properties of the same name,
+/// their values being pixels unless they end with a percent sign. Invalid values are ignored.
+fn find_dimension_attribute_declarations(node: &Node) -> Vec<Declaration> {
+    let NodeType::Element(element) = &node.node_type else {
+        return Vec::new();
+    };
+
+    if element.tag_name() != "img" {
+        return Vec::new();
+    }
+
+    let declarations: Vec<String> = ["width", "height"]
+        .into_iter()
+        .filter_map(|name| {
+            let value = element.attributes().get(name)?.trim();
+            let (number, unit) = match value.strip_suffix('%') {
+                Some(number) => (number, "%"),
+                None => (value, "px"),
+            };
+
+            number
+                .parse::<f32>()
+                .ok()
+                .filter(|number| *number >= 0.)
+                .map(|number| format!("{name}: {number}{unit}"))
+        })
+        .collect();
+
+    crate::css::parse_list_of_declarations(&declarations.join("; "))
+}
+
+fn text_content(node: &Node) -> String {
+    match &node.node_type {
+        NodeType::Text(text) => text.get().to_string(),
+        _ => node.children.iter().map(text_content).collect(),
+    }
+}
+
 fn find_style_attribute_declarations(node: &Node) -> Vec<Declaration> {
     if let NodeType::Element(element) = &node.node_type {
         if let Some(style) = element.attributes().get("style") {
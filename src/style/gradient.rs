@@ -0,0 +1,293 @@
+use crate::{
+    css::types::{Rgb, Value},
+    style::{
+        properties::background_position::BackgroundPosition, utils::keyword_to_rgb,
+        validations::Validations,
+    },
+};
+
+/// A `linear-gradient()`, `radial-gradient()` or `conic-gradient()` image, or one of their
+/// `repeating-` variants.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Gradient {
+    pub(crate) kind: GradientKind,
+    pub(crate) stops: Vec<ColorStop>,
+    pub(crate) repeating: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum GradientKind {
+    Linear(LinearDirection),
+    Radial {
+        shape: RadialShape,
+        size: RadialSize,
+        position: (Value, Value),
+    },
+    Conic {
+        /// The angle of the start of the gradient, in degrees clockwise from the top.
+        from: f32,
+        position: (Value, Value),
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum LinearDirection {
+    /// An angle in degrees, clockwise from the top.
+    Angle(f32),
+    /// A `to` corner, given by the horizontal and vertical signs of its direction.
+    Corner(f32, f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum RadialShape {
+    Circle,
+    Ellipse,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum RadialSize {
+    ClosestSide,
+    ClosestCorner,
+    FarthestSide,
+    FarthestCorner,
+    /// The horizontal and vertical radii, as lengths or percentages.
+    Explicit(Value, Value),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ColorStop {
+    color: Value,
+    /// A length or percentage along the gradient, or an angle for conic gradients.
+    pub(crate) position: Option<Value>,
+}
+
+impl ColorStop {
+    pub(crate) fn color(&self) -> Rgb {
+        match &self.color {
+            Value::Rgb(rgb) => rgb.clone(),
+            Value::Keyword(keyword) => keyword_to_rgb(keyword),
+            _ => panic!("Invalid color stop"),
+        }
+    }
+}
+
+impl Gradient {
+    /// Parses a gradient function, returning `None` for any other value.
+    pub(crate) fn parse(value: &Value) -> Option<Self> {
+        let Value::Function(name, arguments) = value else {
+            return None;
+        };
+
+        let (name, repeating) = match name.strip_prefix("repeating-") {
+            Some(name) => (name, true),
+            None => (name.as_str(), false),
+        };
+
+        let mut groups: Vec<&[Value]> = arguments.split(|v| v == &Value::Comma).collect();
+
+        let is_conic = name == "conic-gradient";
+        let kind = match groups.first() {
+            Some(group) if ColorStop::parse(group, is_conic).is_none() => {
+                let kind = match name {
+                    "linear-gradient" => Gradient::parse_linear(group)?,
+                    "radial-gradient" => Gradient::parse_radial(group)?,
+                    "conic-gradient" => Gradient::parse_conic(group)?,
+                    _ => return None,
+                };
+
+                groups.remove(0);
+
+                kind
+            }
+            _ => match name {
+                "linear-gradient" => GradientKind::Linear(LinearDirection::Angle(180.)),
+                "radial-gradient" => GradientKind::Radial {
+                    shape: RadialShape::Ellipse,
+                    size: RadialSize::FarthestCorner,
+                    position: (Value::Percentage(50.), Value::Percentage(50.)),
+                },
+                "conic-gradient" => GradientKind::Conic {
+                    from: 0.,
+                    position: (Value::Percentage(50.), Value::Percentage(50.)),
+                },
+                _ => return None,
+            },
+        };
+
+        let mut stops = Vec::new();
+
+        for group in groups {
+            stops.extend(ColorStop::parse(group, is_conic)?);
+        }
+
+        if stops.len() < 2 {
+            return None;
+        }
+
+        Some(Gradient {
+            kind,
+            stops,
+            repeating,
+        })
+    }
+
+    fn parse_linear(value: &[Value]) -> Option<GradientKind> {
+        let direction = match value {
+            [angle] if Validations::angle(angle) => {
+                let Value::Dimension(angle, _) = angle else {
+                    return None;
+                };
+
+                LinearDirection::Angle(*angle)
+            }
+            [to, sides @ ..] if Validations::keyword(to, &["to"]) => {
+                let (mut horizontal, mut vertical) = (None, None);
+
+                for side in sides {
+                    let Value::Keyword(side) = side else {
+                        return None;
+                    };
+
+                    match side.as_str() {
+                        "left" if horizontal.is_none() => horizontal = Some(-1.),
+                        "right" if horizontal.is_none() => horizontal = Some(1.),
+                        "top" if vertical.is_none() => vertical = Some(-1.),
+                        "bottom" if vertical.is_none() => vertical = Some(1.),
+                        _ => return None,
+                    }
+                }
+
+                match (horizontal, vertical) {
+                    (Some(x), Some(y)) => LinearDirection::Corner(x, y),
+                    (Some(x), None) => LinearDirection::Angle(if x > 0. { 90. } else { 270. }),
+                    (None, Some(y)) => LinearDirection::Angle(if y > 0. { 180. } else { 0. }),
+                    (None, None) => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        Some(GradientKind::Linear(direction))
+    }
+
+    fn parse_radial(value: &[Value]) -> Option<GradientKind> {
+        let (value, position) = Gradient::split_position(value)?;
+
+        let mut shape = None;
+        let mut size = None;
+        let mut lengths = Vec::new();
+
+        for v in value {
+            match v {
+                Value::Keyword(keyword) if keyword == "circle" && shape.is_none() => {
+                    shape = Some(RadialShape::Circle)
+                }
+                Value::Keyword(keyword) if keyword == "ellipse" && shape.is_none() => {
+                    shape = Some(RadialShape::Ellipse)
+                }
+                Value::Keyword(keyword) if size.is_none() && lengths.is_empty() => {
+                    size = Some(match keyword.as_str() {
+                        "closest-side" => RadialSize::ClosestSide,
+                        "closest-corner" => RadialSize::ClosestCorner,
+                        "farthest-side" => RadialSize::FarthestSide,
+                        "farthest-corner" => RadialSize::FarthestCorner,
+                        _ => return None,
+                    })
+                }
+                _ if size.is_none()
+                    && (Validations::non_negative_length(v)
+                        || Validations::non_negative_percentage(v)) =>
+                {
+                    lengths.push(v.clone())
+                }
+                _ => return None,
+            }
+        }
+
+        // A single length makes a circle, and two make an ellipse. Circles cannot be sized
+        // with percentages.
+        let (shape, size) = match (shape, lengths.as_slice()) {
+            (Some(RadialShape::Circle) | None, [radius]) if Validations::length(radius) => (
+                RadialShape::Circle,
+                RadialSize::Explicit(radius.clone(), radius.clone()),
+            ),
+            (Some(RadialShape::Ellipse) | None, [x, y]) => (
+                RadialShape::Ellipse,
+                RadialSize::Explicit(x.clone(), y.clone()),
+            ),
+            (shape, []) => (
+                shape.unwrap_or(RadialShape::Ellipse),
+                size.unwrap_or(RadialSize::FarthestCorner),
+            ),
+            _ => return None,
+        };
+
+        Some(GradientKind::Radial {
+            shape,
+            size,
+            position,
+        })
+    }
+
+    fn parse_conic(value: &[Value]) -> Option<GradientKind> {
+        let (value, position) = Gradient::split_position(value)?;
+
+        let from = match value {
+            [] => 0.,
+            [from, Value::Dimension(angle, _)]
+                if Validations::keyword(from, &["from"]) && Validations::angle(&value[1]) =>
+            {
+                *angle
+            }
+            _ => return None,
+        };
+
+        Some(GradientKind::Conic { from, position })
+    }
+
+    /// Splits the `at <position>` suffix off the values, defaulting to the center.
+    fn split_position(value: &[Value]) -> Option<(&[Value], (Value, Value))> {
+        let Some(at) = value.iter().position(|v| Validations::keyword(v, &["at"])) else {
+            return Some((value, (Value::Percentage(50.), Value::Percentage(50.))));
+        };
+
+        Some((&value[..at], BackgroundPosition::parse(&value[at + 1..])?))
+    }
+}
+
+impl ColorStop {
+    /// Parses a color followed by zero, one or two positions, each of which makes a stop.
+    fn parse(value: &[Value], is_conic: bool) -> Option<Vec<Self>> {
+        let is_position = |v: &Value| {
+            Validations::percentage(v)
+                || if is_conic {
+                    Validations::angle(v)
+                } else {
+                    Validations::length(v)
+                }
+        };
+
+        let (color, positions) = value.split_first()?;
+
+        if !Validations::color(color) || positions.len() > 2 || !positions.iter().all(is_position) {
+            return None;
+        }
+
+        if positions.is_empty() {
+            return Some(vec![ColorStop {
+                color: color.clone(),
+                position: None,
+            }]);
+        }
+
+        Some(
+            positions
+                .iter()
+                .map(|position| ColorStop {
+                    color: color.clone(),
+                    position: Some(position.clone()),
+                })
+                .collect(),
+        )
+    }
+}
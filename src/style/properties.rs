@@ -1,109 +1,321 @@
 use std::collections::HashMap;
 
+use background::Background;
+use background_clip::BackgroundClip;
 use background_color::BackgroundColor;
+use background_image::BackgroundImage;
+use background_origin::BackgroundOrigin;
+use background_position::BackgroundPosition;
+use background_repeat::BackgroundRepeat;
+use background_size::BackgroundSize;
 use border::Border;
+use border_bottom::BorderBottom;
+use border_bottom_color::BorderBottomColor;
+use border_bottom_left_radius::BorderBottomLeftRadius;
+use border_bottom_right_radius::BorderBottomRightRadius;
+use border_bottom_style::BorderBottomStyle;
+use border_bottom_width::BorderBottomWidth;
 use border_color::BorderColor;
+use border_left::BorderLeft;
+use border_left_color::BorderLeftColor;
+use border_left_style::BorderLeftStyle;
+use border_left_width::BorderLeftWidth;
+use border_radius::BorderRadius;
+use border_right::BorderRight;
+use border_right_color::BorderRightColor;
+use border_right_style::BorderRightStyle;
+use border_right_width::BorderRightWidth;
 use border_style::BorderStyle;
+use border_top::BorderTop;
+use border_top_color::BorderTopColor;
+use border_top_left_radius::BorderTopLeftRadius;
+use border_top_right_radius::BorderTopRightRadius;
+use border_top_style::BorderTopStyle;
+use border_top_width::BorderTopWidth;
 use border_width::BorderWidth;
+use box_decoration_break::BoxDecorationBreak;
+use box_shadow::BoxShadow;
 use color::Color;
+use direction::Direction;
 use display::Display;
+use font::Font;
+use font_family::FontFamily;
 use font_size::FontSize;
+use font_style::FontStyle;
 use font_weight::FontWeight;
 use height::Height;
+use hyphens::Hyphens;
+use letter_spacing::LetterSpacing;
 use line_height::LineHeight;
 use margin::Margin;
 use margin_bottom::MarginBottom;
 use margin_left::MarginLeft;
 use margin_right::MarginRight;
 use margin_top::MarginTop;
+use opacity::Opacity;
+use overflow_wrap::OverflowWrap;
 use padding::Padding;
 use padding_bottom::PaddingBottom;
 use padding_left::PaddingLeft;
 use padding_right::PaddingRight;
 use padding_top::PaddingTop;
+use tab_size::TabSize;
 use text_align::TextAlign;
+use text_decoration::TextDecoration;
+use text_decoration_color::TextDecorationColor;
+use text_decoration_line::TextDecorationLine;
+use text_decoration_style::TextDecorationStyle;
+use text_decoration_thickness::TextDecorationThickness;
+use text_shadow::TextShadow;
+use text_transform::TextTransform;
+use text_wrap::TextWrap;
+use unicode_bidi::UnicodeBidi;
+use vertical_align::VerticalAlign;
+use white_space::WhiteSpace;
+use white_space_collapse::WhiteSpaceCollapse;
 use width::Width;
+use word_break::WordBreak;
+use word_spacing::WordSpacing;
 
 use crate::css::types::Value;
 
+pub(crate) mod background;
+pub(crate) mod background_clip;
 pub(crate) mod background_color;
+pub(crate) mod background_image;
+pub(crate) mod background_origin;
+pub(crate) mod background_position;
+pub(crate) mod background_repeat;
+pub(crate) mod background_size;
 pub(crate) mod border;
+pub(crate) mod border_bottom;
+pub(crate) mod border_bottom_color;
+pub(crate) mod border_bottom_left_radius;
+pub(crate) mod border_bottom_right_radius;
+pub(crate) mod border_bottom_style;
+pub(crate) mod border_bottom_width;
 pub(crate) mod border_color;
+pub(crate) mod border_left;
+pub(crate) mod border_left_color;
+pub(crate) mod border_left_style;
+pub(crate) mod border_left_width;
+pub(crate) mod border_radius;
+pub(crate) mod border_right;
+pub(crate) mod border_right_color;
+pub(crate) mod border_right_style;
+pub(crate) mod border_right_width;
 pub(crate) mod border_style;
+pub(crate) mod border_top;
+pub(crate) mod border_top_color;
+pub(crate) mod border_top_left_radius;
+pub(crate) mod border_top_right_radius;
+pub(crate) mod border_top_style;
+pub(crate) mod border_top_width;
 pub(crate) mod border_width;
+pub(crate) mod box_decoration_break;
+pub(crate) mod box_shadow;
 pub(crate) mod color;
+pub(crate) mod direction;
 pub(crate) mod display;
+pub(crate) mod font;
+pub(crate) mod font_family;
 pub(crate) mod font_size;
+pub(crate) mod font_style;
 pub(crate) mod font_weight;
 pub(crate) mod height;
+pub(crate) mod hyphens;
+pub(crate) mod letter_spacing;
 pub(crate) mod line_height;
 pub(crate) mod margin;
 pub(crate) mod margin_bottom;
 pub(crate) mod margin_left;
 pub(crate) mod margin_right;
 pub(crate) mod margin_top;
+pub(crate) mod opacity;
+pub(crate) mod overflow_wrap;
 pub(crate) mod padding;
 pub(crate) mod padding_bottom;
 pub(crate) mod padding_left;
 pub(crate) mod padding_right;
 pub(crate) mod padding_top;
+pub(crate) mod tab_size;
 pub(crate) mod text_align;
+pub(crate) mod text_decoration;
+pub(crate) mod text_decoration_color;
+pub(crate) mod text_decoration_line;
+pub(crate) mod text_decoration_style;
+pub(crate) mod text_decoration_thickness;
+pub(crate) mod text_shadow;
+pub(crate) mod text_transform;
+pub(crate) mod text_wrap;
+pub(crate) mod unicode_bidi;
+pub(crate) mod vertical_align;
+pub(crate) mod white_space;
+pub(crate) mod white_space_collapse;
 pub(crate) mod width;
+pub(crate) mod word_break;
+pub(crate) mod word_spacing;
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum Property {
+    Background(Background),
     BackgroundColor(BackgroundColor),
+    BackgroundClip(BackgroundClip),
+    BackgroundImage(BackgroundImage),
+    BackgroundOrigin(BackgroundOrigin),
+    BackgroundPosition(BackgroundPosition),
+    BackgroundRepeat(BackgroundRepeat),
+    BackgroundSize(BackgroundSize),
     Border(Border),
     BorderColor(BorderColor),
     BorderStyle(BorderStyle),
     BorderWidth(BorderWidth),
+    BorderRadius(BorderRadius),
+    BorderTopLeftRadius(BorderTopLeftRadius),
+    BorderTopRightRadius(BorderTopRightRadius),
+    BorderBottomRightRadius(BorderBottomRightRadius),
+    BorderBottomLeftRadius(BorderBottomLeftRadius),
+    BorderTop(BorderTop),
+    BorderTopColor(BorderTopColor),
+    BorderTopStyle(BorderTopStyle),
+    BorderTopWidth(BorderTopWidth),
+    BorderRight(BorderRight),
+    BorderRightColor(BorderRightColor),
+    BorderRightStyle(BorderRightStyle),
+    BorderRightWidth(BorderRightWidth),
+    BorderBottom(BorderBottom),
+    BorderBottomColor(BorderBottomColor),
+    BorderBottomStyle(BorderBottomStyle),
+    BorderBottomWidth(BorderBottomWidth),
+    BorderLeft(BorderLeft),
+    BorderLeftColor(BorderLeftColor),
+    BorderLeftStyle(BorderLeftStyle),
+    BorderLeftWidth(BorderLeftWidth),
+    BoxDecorationBreak(BoxDecorationBreak),
+    BoxShadow(BoxShadow),
     Color(Color),
     Display(Display),
+    Direction(Direction),
+    Font(Font),
+    FontFamily(FontFamily),
     FontSize(FontSize),
+    FontStyle(FontStyle),
     FontWeight(FontWeight),
     Height(Height),
+    Hyphens(Hyphens),
+    LetterSpacing(LetterSpacing),
     LineHeight(LineHeight),
     Margin(Margin),
     MarginTop(MarginTop),
     MarginRight(MarginRight),
     MarginBottom(MarginBottom),
     MarginLeft(MarginLeft),
+    Opacity(Opacity),
+    OverflowWrap(OverflowWrap),
     Padding(Padding),
     PaddingTop(PaddingTop),
     PaddingRight(PaddingRight),
     PaddingBottom(PaddingBottom),
     PaddingLeft(PaddingLeft),
+    TabSize(TabSize),
     TextAlign(TextAlign),
+    TextDecoration(TextDecoration),
+    TextDecorationColor(TextDecorationColor),
+    TextDecorationLine(TextDecorationLine),
+    TextDecorationStyle(TextDecorationStyle),
+    TextDecorationThickness(TextDecorationThickness),
+    TextShadow(TextShadow),
+    TextTransform(TextTransform),
+    TextWrap(TextWrap),
+    UnicodeBidi(UnicodeBidi),
+    VerticalAlign(VerticalAlign),
+    WhiteSpace(WhiteSpace),
+    WhiteSpaceCollapse(WhiteSpaceCollapse),
     Width(Width),
+    WordBreak(WordBreak),
+    WordSpacing(WordSpacing),
 }
 
 impl Property {
     pub(crate) fn name(&self) -> &str {
         match self {
+            Property::Background(property) => property.name(),
             Property::BackgroundColor(property) => property.name(),
+            Property::BackgroundClip(property) => property.name(),
+            Property::BackgroundImage(property) => property.name(),
+            Property::BackgroundOrigin(property) => property.name(),
+            Property::BackgroundPosition(property) => property.name(),
+            Property::BackgroundRepeat(property) => property.name(),
+            Property::BackgroundSize(property) => property.name(),
             Property::Border(property) => property.name(),
             Property::BorderColor(property) => property.name(),
             Property::BorderStyle(property) => property.name(),
             Property::BorderWidth(property) => property.name(),
+            Property::BorderRadius(property) => property.name(),
+            Property::BorderTopLeftRadius(property) => property.name(),
+            Property::BorderTopRightRadius(property) => property.name(),
+            Property::BorderBottomRightRadius(property) => property.name(),
+            Property::BorderBottomLeftRadius(property) => property.name(),
+            Property::BorderTop(property) => property.name(),
+            Property::BorderTopColor(property) => property.name(),
+            Property::BorderTopStyle(property) => property.name(),
+            Property::BorderTopWidth(property) => property.name(),
+            Property::BorderRight(property) => property.name(),
+            Property::BorderRightColor(property) => property.name(),
+            Property::BorderRightStyle(property) => property.name(),
+            Property::BorderRightWidth(property) => property.name(),
+            Property::BorderBottom(property) => property.name(),
+            Property::BorderBottomColor(property) => property.name(),
+            Property::BorderBottomStyle(property) => property.name(),
+            Property::BorderBottomWidth(property) => property.name(),
+            Property::BorderLeft(property) => property.name(),
+            Property::BorderLeftColor(property) => property.name(),
+            Property::BorderLeftStyle(property) => property.name(),
+            Property::BorderLeftWidth(property) => property.name(),
+            Property::BoxDecorationBreak(property) => property.name(),
+            Property::BoxShadow(property) => property.name(),
             Property::Color(property) => property.name(),
             Property::Display(property) => property.name(),
+            Property::Direction(property) => property.name(),
+            Property::Font(property) => property.name(),
+            Property::FontFamily(property) => property.name(),
             Property::FontSize(property) => property.name(),
+            Property::FontStyle(property) => property.name(),
             Property::FontWeight(property) => property.name(),
             Property::Height(property) => property.name(),
+            Property::Hyphens(property) => property.name(),
+            Property::LetterSpacing(property) => property.name(),
             Property::LineHeight(property) => property.name(),
             Property::Margin(property) => property.name(),
             Property::MarginTop(property) => property.name(),
             Property::MarginRight(property) => property.name(),
             Property::MarginBottom(property) => property.name(),
             Property::MarginLeft(property) => property.name(),
+            Property::Opacity(property) => property.name(),
+            Property::OverflowWrap(property) => property.name(),
             Property::Padding(property) => property.name(),
             Property::PaddingTop(property) => property.name(),
             Property::PaddingRight(property) => property.name(),
             Property::PaddingBottom(property) => property.name(),
             Property::PaddingLeft(property) => property.name(),
+            Property::TabSize(property) => property.name(),
             Property::TextAlign(property) => property.name(),
+            Property::TextDecoration(property) => property.name(),
+            Property::TextDecorationColor(property) => property.name(),
+            Property::TextDecorationLine(property) => property.name(),
+            Property::TextDecorationStyle(property) => property.name(),
+            Property::TextDecorationThickness(property) => property.name(),
+            Property::TextShadow(property) => property.name(),
+            Property::TextTransform(property) => property.name(),
+            Property::TextWrap(property) => property.name(),
+            Property::UnicodeBidi(property) => property.name(),
+            Property::VerticalAlign(property) => property.name(),
+            Property::WhiteSpace(property) => property.name(),
+            Property::WhiteSpaceCollapse(property) => property.name(),
             Property::Width(property) => property.name(),
+            Property::WordBreak(property) => property.name(),
+            Property::WordSpacing(property) => property.name(),
         }
     }
 }
@@ -125,29 +337,82 @@ impl PropertyRegistry {
             available_properties: Vec::new(),
         };
 
+        property_builder.register(Box::new(Background::new()));
         property_builder.register(Box::new(BackgroundColor::new()));
+        property_builder.register(Box::new(BackgroundClip::new()));
+        property_builder.register(Box::new(BackgroundImage::new()));
+        property_builder.register(Box::new(BackgroundOrigin::new()));
+        property_builder.register(Box::new(BackgroundPosition::new()));
+        property_builder.register(Box::new(BackgroundRepeat::new()));
+        property_builder.register(Box::new(BackgroundSize::new()));
         property_builder.register(Box::new(Border::new()));
         property_builder.register(Box::new(BorderStyle::new()));
         property_builder.register(Box::new(BorderWidth::new()));
         property_builder.register(Box::new(BorderColor::new()));
+        property_builder.register(Box::new(BorderRadius::new()));
+        property_builder.register(Box::new(BorderTopLeftRadius::new()));
+        property_builder.register(Box::new(BorderTopRightRadius::new()));
+        property_builder.register(Box::new(BorderBottomRightRadius::new()));
+        property_builder.register(Box::new(BorderBottomLeftRadius::new()));
+        property_builder.register(Box::new(BorderTop::new()));
+        property_builder.register(Box::new(BorderTopColor::new()));
+        property_builder.register(Box::new(BorderTopStyle::new()));
+        property_builder.register(Box::new(BorderTopWidth::new()));
+        property_builder.register(Box::new(BorderRight::new()));
+        property_builder.register(Box::new(BorderRightColor::new()));
+        property_builder.register(Box::new(BorderRightStyle::new()));
+        property_builder.register(Box::new(BorderRightWidth::new()));
+        property_builder.register(Box::new(BorderBottom::new()));
+        property_builder.register(Box::new(BorderBottomColor::new()));
+        property_builder.register(Box::new(BorderBottomStyle::new()));
+        property_builder.register(Box::new(BorderBottomWidth::new()));
+        property_builder.register(Box::new(BorderLeft::new()));
+        property_builder.register(Box::new(BorderLeftColor::new()));
+        property_builder.register(Box::new(BorderLeftStyle::new()));
+        property_builder.register(Box::new(BorderLeftWidth::new()));
+        property_builder.register(Box::new(BoxDecorationBreak::new()));
+        property_builder.register(Box::new(BoxShadow::new()));
         property_builder.register(Box::new(Color::new()));
         property_builder.register(Box::new(Display::new()));
+        property_builder.register(Box::new(Direction::new()));
+        property_builder.register(Box::new(Font::new()));
+        property_builder.register(Box::new(FontFamily::new()));
         property_builder.register(Box::new(FontSize::new()));
+        property_builder.register(Box::new(FontStyle::new()));
         property_builder.register(Box::new(FontWeight::new()));
         property_builder.register(Box::new(Height::new()));
+        property_builder.register(Box::new(Hyphens::new()));
+        property_builder.register(Box::new(LetterSpacing::new()));
         property_builder.register(Box::new(LineHeight::new()));
         property_builder.register(Box::new(Margin::new()));
         property_builder.register(Box::new(MarginTop::new()));
         property_builder.register(Box::new(MarginRight::new()));
         property_builder.register(Box::new(MarginBottom::new()));
         property_builder.register(Box::new(MarginLeft::new()));
+        property_builder.register(Box::new(Opacity::new()));
+        property_builder.register(Box::new(OverflowWrap::new()));
         property_builder.register(Box::new(Padding::new()));
         property_builder.register(Box::new(PaddingTop::new()));
         property_builder.register(Box::new(PaddingRight::new()));
         property_builder.register(Box::new(PaddingBottom::new()));
         property_builder.register(Box::new(PaddingLeft::new()));
+        property_builder.register(Box::new(TabSize::new()));
         property_builder.register(Box::new(TextAlign::new()));
+        property_builder.register(Box::new(TextDecoration::new()));
+        property_builder.register(Box::new(TextDecorationColor::new()));
+        property_builder.register(Box::new(TextDecorationLine::new()));
+        property_builder.register(Box::new(TextDecorationStyle::new()));
+        property_builder.register(Box::new(TextDecorationThickness::new()));
+        property_builder.register(Box::new(TextShadow::new()));
+        property_builder.register(Box::new(TextTransform::new()));
+        property_builder.register(Box::new(TextWrap::new()));
+        property_builder.register(Box::new(UnicodeBidi::new()));
+        property_builder.register(Box::new(VerticalAlign::new()));
+        property_builder.register(Box::new(WhiteSpace::new()));
+        property_builder.register(Box::new(WhiteSpaceCollapse::new()));
         property_builder.register(Box::new(Width::new()));
+        property_builder.register(Box::new(WordBreak::new()));
+        property_builder.register(Box::new(WordSpacing::new()));
 
         property_builder
     }
@@ -244,3 +509,81 @@ trait CssProperty {
     /// returning `None` if the conversion is not possible.
     fn maybe_new(&self, value: &[Value]) -> Vec<Property>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css;
+
+    /// Creates the properties set by a list of declarations.
+    fn create(declarations: &str) -> Vec<Property> {
+        let registry = PropertyRegistry::new();
+
+        css::parse_list_of_declarations(declarations)
+            .iter()
+            .flat_map(|declaration| registry.create(&declaration.name, &declaration.value))
+            .collect()
+    }
+
+    #[test]
+    fn test_keep_negative_shadow_offsets_and_drop_negative_blur_radii() {
+        assert_eq!(create("box-shadow: -2px -2px 4px -1px black").len(), 1);
+        assert_eq!(create("text-shadow: -2px -2px black").len(), 1);
+        assert!(create("box-shadow: 2px 2px -4px black").is_empty());
+        assert!(create("text-shadow: 2px 2px -4px black").is_empty());
+    }
+
+    #[test]
+    fn test_drop_negative_font_sizes() {
+        assert_eq!(create("font-size: 0").len(), 1);
+        assert!(create("font-size: -10px").is_empty());
+        assert!(create("font: -10px serif").is_empty());
+    }
+
+    #[test]
+    fn test_drop_negative_border_widths() {
+        assert_eq!(create("border-width: 0 1px thin 2px").len(), 4);
+        assert!(create("border-width: -1px").is_empty());
+        assert!(create("border-top-width: -1px").is_empty());
+        assert!(create("border: -1px solid black").is_empty());
+    }
+
+    #[test]
+    fn test_drop_negative_border_radii() {
+        assert_eq!(create("border-radius: 0 4px 50% 2px / 3px").len(), 4);
+        assert!(create("border-radius: -4px").is_empty());
+        assert!(create("border-radius: 4px / -10%").is_empty());
+        assert!(create("border-top-left-radius: 4px -4px").is_empty());
+    }
+
+    #[test]
+    fn test_drop_negative_background_sizes() {
+        assert_eq!(create("background-size: 50% auto").len(), 1);
+        assert!(create("background-size: -10px").is_empty());
+        assert!(create("background-size: auto -50%").is_empty());
+    }
+
+    #[test]
+    fn test_drop_radial_gradients_with_negative_sizes() {
+        assert_eq!(
+            create("background-image: radial-gradient(10px, red, blue)").len(),
+            1
+        );
+        assert!(create("background-image: radial-gradient(-10px, red, blue)").is_empty());
+        assert!(create("background-image: radial-gradient(10px -20%, red, blue)").is_empty());
+    }
+
+    #[test]
+    fn test_drop_negative_padding() {
+        assert_eq!(create("padding: 0 1px 2% 3px").len(), 4);
+        assert!(create("padding: -1px").is_empty());
+        assert!(create("padding-left: -10%").is_empty());
+    }
+
+    #[test]
+    fn test_drop_negative_widths_and_heights() {
+        assert_eq!(create("width: 50%; height: 0").len(), 2);
+        assert!(create("width: -10px").is_empty());
+        assert!(create("height: -50%").is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{
+    background_clip::BackgroundClip, background_color::BackgroundColor,
+    background_image::BackgroundImage, background_origin::BackgroundOrigin,
+    background_position::BackgroundPosition, background_repeat::BackgroundRepeat,
+    background_size::BackgroundSize, CssProperty, Property,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Background;
+
+impl Background {
+    pub(crate) fn new() -> Self {
+        Background
+    }
+
+    /// Returns the length of the run of at most two values at the start of `value` that pass
+    /// the validation.
+    fn run_length(value: &[Value], is_valid: impl Fn(&Value) -> bool) -> usize {
+        value.iter().take(2).take_while(|v| is_valid(v)).count()
+    }
+
+    /// Returns the longhand for the values found in the shorthand, or its initial value when
+    /// none were. `None` means the values are invalid for the longhand.
+    fn longhand(property: &dyn CssProperty, value: Option<&[Value]>) -> Option<Vec<Property>> {
+        let Some(value) = value else {
+            return Some(property.initial_value());
+        };
+
+        let properties = property.maybe_new(value);
+
+        (!properties.is_empty()).then_some(properties)
+    }
+}
+
+impl CssProperty for Background {
+    fn name(&self) -> &'static str {
+        "background"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        true
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        let mut ans = Vec::new();
+
+        ans.extend(BackgroundColor::new().initial_value());
+        ans.extend(BackgroundImage::new().initial_value());
+        ans.extend(BackgroundRepeat::new().initial_value());
+        ans.extend(BackgroundPosition::new().initial_value());
+        ans.extend(BackgroundSize::new().initial_value());
+        ans.extend(BackgroundOrigin::new().initial_value());
+        ans.extend(BackgroundClip::new().initial_value());
+
+        ans
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.is_empty() {
+            return Vec::new();
+        }
+
+        let (mut color, mut image, mut repeat, mut position, mut size) =
+            (None, None, None, None, None);
+        let mut boxes = Vec::new();
+        let mut i = 0;
+
+        while i < value.len() {
+            let v = &value[i];
+
+            if color.is_none() && Validations::color(v) {
+                color = Some(&value[i..i + 1]);
+                i += 1;
+            } else if image.is_none() && BackgroundImage::is_valid(v) {
+                image = Some(&value[i..i + 1]);
+                i += 1;
+            } else if repeat.is_none() && BackgroundRepeat::is_valid(v) {
+                let length = Background::run_length(&value[i..], BackgroundRepeat::is_valid);
+
+                repeat = Some(&value[i..i + length]);
+                i += length;
+            } else if position.is_none() && BackgroundPosition::is_valid(v) {
+                let length = Background::run_length(&value[i..], BackgroundPosition::is_valid);
+
+                position = Some(&value[i..i + length]);
+                i += length;
+
+                // The size can only follow the position, separated by a slash.
+                if value.get(i) == Some(&Value::Slash) {
+                    let length = Background::run_length(&value[i + 1..], |v| {
+                        BackgroundSize::is_valid(v)
+                            || Validations::keyword(v, &["cover", "contain"])
+                    });
+
+                    if length == 0 {
+                        return Vec::new();
+                    }
+
+                    size = Some(&value[i + 1..i + 1 + length]);
+                    i += 1 + length;
+                }
+            } else if boxes.len() < 2 && Validations::box_area(v) {
+                boxes.push(v.clone());
+                i += 1;
+            } else {
+                return Vec::new();
+            }
+        }
+
+        // A single box sets both the origin and the clip.
+        let origin = boxes.first().map(std::slice::from_ref);
+        let clip = boxes.last().map(std::slice::from_ref);
+
+        let longhands = [
+            Background::longhand(&BackgroundColor::new(), color),
+            Background::longhand(&BackgroundImage::new(), image),
+            Background::longhand(&BackgroundRepeat::new(), repeat),
+            Background::longhand(&BackgroundPosition::new(), position),
+            Background::longhand(&BackgroundSize::new(), size),
+            Background::longhand(&BackgroundOrigin::new(), origin),
+            Background::longhand(&BackgroundClip::new(), clip),
+        ];
+
+        let mut ans = Vec::new();
+
+        for longhand in longhands {
+            let Some(properties) = longhand else {
+                return Vec::new();
+            };
+
+            ans.extend(properties);
+        }
+
+        ans
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{background_origin::BoxArea, CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BackgroundClip {
+    value: Value,
+}
+
+impl BackgroundClip {
+    pub(super) fn new() -> Self {
+        BackgroundClip {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> BoxArea {
+        (&self.value).into()
+    }
+}
+
+impl CssProperty for BackgroundClip {
+    fn name(&self) -> &'static str {
+        "background-clip"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BackgroundClip(BackgroundClip {
+            value: Value::Keyword("border-box".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.len() != 1 {
+            return Vec::new();
+        }
+
+        let value = value.first().unwrap();
+
+        if Validations::box_area(value) {
+            return vec![Property::BackgroundClip(BackgroundClip {
+                value: value.clone(),
+            })];
+        }
+
+        Vec::new()
+    }
+}
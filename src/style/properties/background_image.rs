@@ -0,0 +1,67 @@
+use crate::{css::types::Value, style::gradient::Gradient};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Image {
+    Url(String),
+    Gradient(Box<Gradient>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BackgroundImage {
+    value: Option<Image>,
+}
+
+impl BackgroundImage {
+    pub(super) fn new() -> Self {
+        BackgroundImage { value: None }
+    }
+
+    /// Returns the image, or `None` for `none`.
+    pub(crate) fn actual_value(&self) -> Option<&Image> {
+        self.value.as_ref()
+    }
+
+    pub(super) fn is_valid(value: &Value) -> bool {
+        BackgroundImage::parse(value).is_some()
+    }
+
+    /// Parses an image value, where `Some(None)` stands for `none`.
+    fn parse(value: &Value) -> Option<Option<Image>> {
+        match value {
+            Value::Url(url) => Some(Some(Image::Url(url.clone()))),
+            Value::Keyword(keyword) if keyword == "none" => Some(None),
+            _ => Gradient::parse(value).map(|gradient| Some(Image::Gradient(Box::new(gradient)))),
+        }
+    }
+}
+
+impl CssProperty for BackgroundImage {
+    fn name(&self) -> &'static str {
+        "background-image"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BackgroundImage(BackgroundImage { value: None })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        let [value] = value else {
+            return Vec::new();
+        };
+
+        match BackgroundImage::parse(value) {
+            Some(image) => vec![Property::BackgroundImage(BackgroundImage { value: image })],
+            None => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+/// One of the boxes of an element, used to position and clip its background.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BoxArea {
+    Border,
+    Padding,
+    Content,
+}
+
+impl From<&Value> for BoxArea {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Keyword(keyword) if keyword == "border-box" => BoxArea::Border,
+            Value::Keyword(keyword) if keyword == "content-box" => BoxArea::Content,
+            _ => BoxArea::Padding,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BackgroundOrigin {
+    value: Value,
+}
+
+impl BackgroundOrigin {
+    pub(super) fn new() -> Self {
+        BackgroundOrigin {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> BoxArea {
+        (&self.value).into()
+    }
+}
+
+impl CssProperty for BackgroundOrigin {
+    fn name(&self) -> &'static str {
+        "background-origin"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BackgroundOrigin(BackgroundOrigin {
+            value: Value::Keyword("padding-box".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.len() != 1 {
+            return Vec::new();
+        }
+
+        let value = value.first().unwrap();
+
+        if Validations::box_area(value) {
+            return vec![Property::BackgroundOrigin(BackgroundOrigin {
+                value: value.clone(),
+            })];
+        }
+
+        Vec::new()
+    }
+}
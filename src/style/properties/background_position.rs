@@ -0,0 +1,118 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BackgroundPosition {
+    horizontal: Value,
+    vertical: Value,
+}
+
+impl BackgroundPosition {
+    pub(super) fn new() -> Self {
+        BackgroundPosition {
+            horizontal: Value::default(),
+            vertical: Value::default(),
+        }
+    }
+
+    /// Returns the offset of the image from the top-left corner of the positioning area.
+    /// Percentages align the same point of the image and the area.
+    pub(crate) fn actual_value(
+        &self,
+        area_width: f32,
+        area_height: f32,
+        image_width: f32,
+        image_height: f32,
+    ) -> (f32, f32) {
+        let resolve = |value: &Value, free_space: f32| match value {
+            Value::Dimension(value, _) => *value,
+            Value::Percentage(value) => free_space * value / 100.,
+            _ => 0.,
+        };
+
+        (
+            resolve(&self.horizontal, area_width - image_width),
+            resolve(&self.vertical, area_height - image_height),
+        )
+    }
+
+    pub(super) fn is_valid(value: &Value) -> bool {
+        Validations::length(value)
+            || Validations::percentage(value)
+            || Validations::keyword(value, &["left", "center", "right", "top", "bottom"])
+    }
+
+    /// Parses a one or two value position into its horizontal and vertical components, as
+    /// lengths or percentages.
+    pub(crate) fn parse(value: &[Value]) -> Option<(Value, Value)> {
+        if !value.iter().all(BackgroundPosition::is_valid) {
+            return None;
+        }
+
+        let center = Value::Keyword("center".to_string());
+        let is_vertical = |v: &Value| Validations::keyword(v, &["top", "bottom"]);
+        let is_horizontal = |v: &Value| Validations::keyword(v, &["left", "right"]);
+
+        let (horizontal, vertical) = match value {
+            [v] if is_vertical(v) => (&center, v),
+            [v] => (v, &center),
+            [h, v] if is_vertical(h) || is_horizontal(v) => (v, h),
+            [h, v] => (h, v),
+            _ => return None,
+        };
+
+        if is_vertical(horizontal) || is_horizontal(vertical) {
+            return None;
+        }
+
+        Some((
+            BackgroundPosition::keyword_to_percentage(horizontal),
+            BackgroundPosition::keyword_to_percentage(vertical),
+        ))
+    }
+
+    /// Converts a keyword to the percentage it stands for.
+    fn keyword_to_percentage(value: &Value) -> Value {
+        match value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "left" | "top" => Value::Percentage(0.),
+                "right" | "bottom" => Value::Percentage(100.),
+                _ => Value::Percentage(50.),
+            },
+            _ => value.clone(),
+        }
+    }
+}
+
+impl CssProperty for BackgroundPosition {
+    fn name(&self) -> &'static str {
+        "background-position"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BackgroundPosition(BackgroundPosition {
+            horizontal: Value::Percentage(0.),
+            vertical: Value::Percentage(0.),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        let Some((horizontal, vertical)) = BackgroundPosition::parse(value) else {
+            return Vec::new();
+        };
+
+        vec![Property::BackgroundPosition(BackgroundPosition {
+            horizontal,
+            vertical,
+        })]
+    }
+}
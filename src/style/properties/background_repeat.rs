@@ -0,0 +1,106 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+/// How a background image is repeated along one axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum RepeatStyle {
+    Repeat,
+    NoRepeat,
+    /// Repeats the image as often as it fits whole, spreading the copies apart.
+    Space,
+    /// Repeats the image a whole number of times, rescaling it to fill the area.
+    Round,
+}
+
+impl From<&Value> for RepeatStyle {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Keyword(keyword) if keyword == "no-repeat" => RepeatStyle::NoRepeat,
+            Value::Keyword(keyword) if keyword == "space" => RepeatStyle::Space,
+            Value::Keyword(keyword) if keyword == "round" => RepeatStyle::Round,
+            _ => RepeatStyle::Repeat,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BackgroundRepeat {
+    horizontal: Value,
+    vertical: Value,
+}
+
+impl BackgroundRepeat {
+    pub(super) fn new() -> Self {
+        BackgroundRepeat {
+            horizontal: Value::default(),
+            vertical: Value::default(),
+        }
+    }
+
+    /// Returns how the image repeats horizontally and vertically.
+    pub(crate) fn actual_value(&self) -> (RepeatStyle, RepeatStyle) {
+        ((&self.horizontal).into(), (&self.vertical).into())
+    }
+
+    pub(super) fn is_valid(value: &Value) -> bool {
+        Validations::keyword(
+            value,
+            &[
+                "repeat-x",
+                "repeat-y",
+                "repeat",
+                "space",
+                "round",
+                "no-repeat",
+            ],
+        )
+    }
+}
+
+impl CssProperty for BackgroundRepeat {
+    fn name(&self) -> &'static str {
+        "background-repeat"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BackgroundRepeat(BackgroundRepeat {
+            horizontal: Value::Keyword("repeat".to_string()),
+            vertical: Value::Keyword("repeat".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        let single_axis = ["repeat-x", "repeat-y"];
+        let repeat = Value::Keyword("repeat".to_string());
+        let no_repeat = Value::Keyword("no-repeat".to_string());
+
+        let (horizontal, vertical) = match value {
+            [v] if Validations::keyword(v, &["repeat-x"]) => (repeat, no_repeat),
+            [v] if Validations::keyword(v, &["repeat-y"]) => (no_repeat, repeat),
+            [v] if BackgroundRepeat::is_valid(v) => (v.clone(), v.clone()),
+            [h, v]
+                if BackgroundRepeat::is_valid(h)
+                    && BackgroundRepeat::is_valid(v)
+                    && !Validations::keyword(h, &single_axis)
+                    && !Validations::keyword(v, &single_axis) =>
+            {
+                (h.clone(), v.clone())
+            }
+            _ => return Vec::new(),
+        };
+
+        vec![Property::BackgroundRepeat(BackgroundRepeat {
+            horizontal,
+            vertical,
+        })]
+    }
+}
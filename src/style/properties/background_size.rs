@@ -0,0 +1,120 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BackgroundSize {
+    width: Value,
+    height: Value,
+}
+
+impl BackgroundSize {
+    pub(super) fn new() -> Self {
+        BackgroundSize {
+            width: Value::default(),
+            height: Value::default(),
+        }
+    }
+
+    /// Returns the size of the image in a positioning area of the given size. `auto` keeps the
+    /// aspect ratio of the image, and `cover` and `contain` scale it to the largest size that
+    /// respectively fills the area or fits inside it. Images without an intrinsic size, like
+    /// gradients, fill the area by default.
+    pub(crate) fn actual_value(
+        &self,
+        area_width: f32,
+        area_height: f32,
+        intrinsic_size: Option<(f32, f32)>,
+    ) -> (f32, f32) {
+        let Some((image_width, image_height)) = intrinsic_size else {
+            return match (&self.width, &self.height) {
+                (Value::Keyword(keyword), _) if keyword != "auto" => (area_width, area_height),
+                (width, height) => (
+                    BackgroundSize::resolve(width, area_width).unwrap_or(area_width),
+                    BackgroundSize::resolve(height, area_height).unwrap_or(area_height),
+                ),
+            };
+        };
+
+        if image_width <= 0. || image_height <= 0. {
+            return (0., 0.);
+        }
+
+        if let Value::Keyword(keyword) = &self.width {
+            let (horizontal_scale, vertical_scale) =
+                (area_width / image_width, area_height / image_height);
+
+            let scale = match keyword.as_str() {
+                "cover" => horizontal_scale.max(vertical_scale),
+                "contain" => horizontal_scale.min(vertical_scale),
+                _ => 1.,
+            };
+
+            if keyword != "auto" {
+                return (image_width * scale, image_height * scale);
+            }
+        }
+
+        match (
+            BackgroundSize::resolve(&self.width, area_width),
+            BackgroundSize::resolve(&self.height, area_height),
+        ) {
+            (Some(width), Some(height)) => (width, height),
+            (Some(width), None) => (width, width * image_height / image_width),
+            (None, Some(height)) => (height * image_width / image_height, height),
+            (None, None) => (image_width, image_height),
+        }
+    }
+
+    /// Resolves a length or percentage, returning `None` for `auto`.
+    fn resolve(value: &Value, reference: f32) -> Option<f32> {
+        match value {
+            Value::Dimension(value, _) => Some(*value),
+            Value::Percentage(value) => Some(reference * value / 100.),
+            _ => None,
+        }
+    }
+
+    pub(super) fn is_valid(value: &Value) -> bool {
+        Validations::non_negative_length(value)
+            || Validations::non_negative_percentage(value)
+            || Validations::keyword(value, &["auto"])
+    }
+}
+
+impl CssProperty for BackgroundSize {
+    fn name(&self) -> &'static str {
+        "background-size"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BackgroundSize(BackgroundSize {
+            width: Value::Keyword("auto".to_string()),
+            height: Value::Keyword("auto".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        let auto = Value::Keyword("auto".to_string());
+
+        let (width, height) = match value {
+            [v] if Validations::keyword(v, &["cover", "contain"]) => (v, &auto),
+            [v] if BackgroundSize::is_valid(v) => (v, &auto),
+            [w, h] if BackgroundSize::is_valid(w) && BackgroundSize::is_valid(h) => (w, h),
+            _ => return Vec::new(),
+        };
+
+        vec![Property::BackgroundSize(BackgroundSize {
+            width: width.clone(),
+            height: height.clone(),
+        })]
+    }
+}
@@ -53,21 +53,21 @@ impl CssProperty for Border {
                 }
 
                 border_width_found = true;
-                ans.extend(BorderWidth::new().maybe_new(&[v.clone()]));
+                ans.extend(BorderWidth::new().maybe_new(std::slice::from_ref(v)));
             } else if Validations::border_style(v) {
                 if border_style_found {
                     return Vec::new();
                 }
 
                 border_style_found = true;
-                ans.extend(BorderStyle::new().maybe_new(&[v.clone()]));
+                ans.extend(BorderStyle::new().maybe_new(std::slice::from_ref(v)));
             } else if Validations::color(v) {
                 if border_color_found {
                     return Vec::new();
                 }
 
                 border_color_found = true;
-                ans.extend(BorderColor::new().maybe_new(&[v.clone()]));
+                ans.extend(BorderColor::new().maybe_new(std::slice::from_ref(v)));
             } else {
                 return Vec::new();
             }
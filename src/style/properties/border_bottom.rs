@@ -0,0 +1,88 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{
+    border_bottom_color::BorderBottomColor, border_bottom_style::BorderBottomStyle,
+    border_bottom_width::BorderBottomWidth, CssProperty, Property,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BorderBottom;
+
+impl BorderBottom {
+    pub(crate) fn new() -> Self {
+        BorderBottom
+    }
+}
+
+impl CssProperty for BorderBottom {
+    fn name(&self) -> &'static str {
+        "border-bottom"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        true
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        let mut ans = Vec::new();
+
+        ans.extend(BorderBottomWidth::new().initial_value());
+        ans.extend(BorderBottomStyle::new().initial_value());
+        ans.extend(BorderBottomColor::new().initial_value());
+
+        ans
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.is_empty() || value.len() > 3 {
+            return Vec::new();
+        }
+
+        let mut ans = Vec::new();
+        let (mut border_width_found, mut border_style_found, mut border_color_found) =
+            (false, false, false);
+
+        for v in value {
+            if Validations::border_width(v) {
+                if border_width_found {
+                    return Vec::new();
+                }
+
+                border_width_found = true;
+                ans.extend(BorderBottomWidth::new().maybe_new(std::slice::from_ref(v)));
+            } else if Validations::border_style(v) {
+                if border_style_found {
+                    return Vec::new();
+                }
+
+                border_style_found = true;
+                ans.extend(BorderBottomStyle::new().maybe_new(std::slice::from_ref(v)));
+            } else if Validations::color(v) {
+                if border_color_found {
+                    return Vec::new();
+                }
+
+                border_color_found = true;
+                ans.extend(BorderBottomColor::new().maybe_new(std::slice::from_ref(v)));
+            } else {
+                return Vec::new();
+            }
+        }
+
+        if !border_width_found {
+            ans.extend(BorderBottomWidth::new().initial_value());
+        }
+        if !border_style_found {
+            ans.extend(BorderBottomStyle::new().initial_value());
+        }
+        if !border_color_found {
+            ans.extend(BorderBottomColor::new().initial_value());
+        }
+
+        ans
+    }
+}
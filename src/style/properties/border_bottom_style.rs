@@ -0,0 +1,56 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BorderBottomStyle {
+    value: Value,
+}
+
+impl BorderBottomStyle {
+    pub(super) fn new() -> Self {
+        BorderBottomStyle {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> &Value {
+        &self.value
+    }
+}
+
+impl CssProperty for BorderBottomStyle {
+    fn name(&self) -> &'static str {
+        "border-bottom-style"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BorderBottomStyle(BorderBottomStyle {
+            value: Value::Keyword("none".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.len() != 1 {
+            return Vec::new();
+        }
+
+        let value = value.first().unwrap();
+
+        if Validations::border_style(value) {
+            return vec![Property::BorderBottomStyle(BorderBottomStyle {
+                value: value.clone(),
+            })];
+        }
+
+        Vec::new()
+    }
+}
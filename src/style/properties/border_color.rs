@@ -1,29 +1,30 @@
-use crate::{
-    css::types::{Rgb, Value},
-    style::{utils::keyword_to_rgb, validations::Validations},
-};
+use crate::{css::types::Value, style::validations::Validations};
 
-use super::{CssProperty, Property};
+use super::{
+    border_bottom_color::BorderBottomColor, border_left_color::BorderLeftColor,
+    border_right_color::BorderRightColor, border_top_color::BorderTopColor, CssProperty, Property,
+};
 
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) struct BorderColor {
-    value: Value,
-}
+pub(crate) struct BorderColor;
 
 impl BorderColor {
     pub(super) fn new() -> Self {
-        BorderColor {
-            value: Value::default(),
-        }
+        BorderColor
     }
 
-    pub(crate) fn actual_value(&self, color: &Rgb) -> Rgb {
-        match &self.value {
-            Value::Rgb(rgb) => rgb.clone(),
-            Value::Keyword(keyword) => keyword_to_rgb(keyword),
-            Value::NotDeclared => color.clone(),
-            _ => panic!("Unexpected value for border-color"),
-        }
+    fn create_border_color_properties_with_values(
+        &self,
+        values: (Value, Value, Value, Value),
+    ) -> Vec<Property> {
+        let mut ans = Vec::new();
+
+        ans.extend(BorderTopColor::new().maybe_new(&[values.0]));
+        ans.extend(BorderRightColor::new().maybe_new(&[values.1]));
+        ans.extend(BorderBottomColor::new().maybe_new(&[values.2]));
+        ans.extend(BorderLeftColor::new().maybe_new(&[values.3]));
+
+        ans
     }
 }
 
@@ -37,28 +38,58 @@ impl CssProperty for BorderColor {
     }
 
     fn is_shorthand(&self) -> bool {
-        false
+        true
     }
 
     fn initial_value(&self) -> Vec<Property> {
-        vec![Property::BorderColor(BorderColor {
-            value: Value::NotDeclared,
-        })]
+        let mut ans = Vec::new();
+
+        ans.extend(BorderTopColor::new().initial_value());
+        ans.extend(BorderRightColor::new().initial_value());
+        ans.extend(BorderBottomColor::new().initial_value());
+        ans.extend(BorderLeftColor::new().initial_value());
+
+        ans
     }
 
     fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
-        if value.len() != 1 {
+        if value.is_empty() || value.len() > 4 {
             return Vec::new();
         }
 
-        let value = value.first().unwrap();
-
-        if Validations::color(value) {
-            return vec![Property::BorderColor(BorderColor {
-                value: value.clone(),
-            })];
+        for val in value {
+            if !Validations::color(val) {
+                return Vec::new();
+            }
         }
 
-        Vec::new()
+        let values = match value.len() {
+            1 => (
+                value[0].clone(),
+                value[0].clone(),
+                value[0].clone(),
+                value[0].clone(),
+            ),
+            2 => (
+                value[0].clone(),
+                value[1].clone(),
+                value[0].clone(),
+                value[1].clone(),
+            ),
+            3 => (
+                value[0].clone(),
+                value[1].clone(),
+                value[2].clone(),
+                value[1].clone(),
+            ),
+            _ => (
+                value[0].clone(),
+                value[1].clone(),
+                value[2].clone(),
+                value[3].clone(),
+            ),
+        };
+
+        self.create_border_color_properties_with_values(values)
     }
 }
@@ -0,0 +1,88 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{
+    border_left_color::BorderLeftColor, border_left_style::BorderLeftStyle,
+    border_left_width::BorderLeftWidth, CssProperty, Property,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BorderLeft;
+
+impl BorderLeft {
+    pub(crate) fn new() -> Self {
+        BorderLeft
+    }
+}
+
+impl CssProperty for BorderLeft {
+    fn name(&self) -> &'static str {
+        "border-left"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        true
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        let mut ans = Vec::new();
+
+        ans.extend(BorderLeftWidth::new().initial_value());
+        ans.extend(BorderLeftStyle::new().initial_value());
+        ans.extend(BorderLeftColor::new().initial_value());
+
+        ans
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.is_empty() || value.len() > 3 {
+            return Vec::new();
+        }
+
+        let mut ans = Vec::new();
+        let (mut border_width_found, mut border_style_found, mut border_color_found) =
+            (false, false, false);
+
+        for v in value {
+            if Validations::border_width(v) {
+                if border_width_found {
+                    return Vec::new();
+                }
+
+                border_width_found = true;
+                ans.extend(BorderLeftWidth::new().maybe_new(std::slice::from_ref(v)));
+            } else if Validations::border_style(v) {
+                if border_style_found {
+                    return Vec::new();
+                }
+
+                border_style_found = true;
+                ans.extend(BorderLeftStyle::new().maybe_new(std::slice::from_ref(v)));
+            } else if Validations::color(v) {
+                if border_color_found {
+                    return Vec::new();
+                }
+
+                border_color_found = true;
+                ans.extend(BorderLeftColor::new().maybe_new(std::slice::from_ref(v)));
+            } else {
+                return Vec::new();
+            }
+        }
+
+        if !border_width_found {
+            ans.extend(BorderLeftWidth::new().initial_value());
+        }
+        if !border_style_found {
+            ans.extend(BorderLeftStyle::new().initial_value());
+        }
+        if !border_color_found {
+            ans.extend(BorderLeftColor::new().initial_value());
+        }
+
+        ans
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BorderLeftStyle {
+    value: Value,
+}
+
+impl BorderLeftStyle {
+    pub(super) fn new() -> Self {
+        BorderLeftStyle {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> &Value {
+        &self.value
+    }
+}
+
+impl CssProperty for BorderLeftStyle {
+    fn name(&self) -> &'static str {
+        "border-left-style"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BorderLeftStyle(BorderLeftStyle {
+            value: Value::Keyword("none".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.len() != 1 {
+            return Vec::new();
+        }
+
+        let value = value.first().unwrap();
+
+        if Validations::border_style(value) {
+            return vec![Property::BorderLeftStyle(BorderLeftStyle {
+                value: value.clone(),
+            })];
+        }
+
+        Vec::new()
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{
+    border_bottom_left_radius::BorderBottomLeftRadius,
+    border_bottom_right_radius::BorderBottomRightRadius,
+    border_top_left_radius::BorderTopLeftRadius, border_top_right_radius::BorderTopRightRadius,
+    CssProperty, Property,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BorderRadius;
+
+impl BorderRadius {
+    pub(crate) fn new() -> Self {
+        BorderRadius
+    }
+
+    /// Expands one to four values into the top-left, top-right, bottom-right and bottom-left
+    /// corners, following the same pattern as `margin`.
+    fn expand(value: &[Value]) -> Option<[Value; 4]> {
+        for v in value {
+            if !Validations::non_negative_length(v) && !Validations::non_negative_percentage(v) {
+                return None;
+            }
+        }
+
+        match value.len() {
+            1 => Some([
+                value[0].clone(),
+                value[0].clone(),
+                value[0].clone(),
+                value[0].clone(),
+            ]),
+            2 => Some([
+                value[0].clone(),
+                value[1].clone(),
+                value[0].clone(),
+                value[1].clone(),
+            ]),
+            3 => Some([
+                value[0].clone(),
+                value[1].clone(),
+                value[2].clone(),
+                value[1].clone(),
+            ]),
+            4 => Some([
+                value[0].clone(),
+                value[1].clone(),
+                value[2].clone(),
+                value[3].clone(),
+            ]),
+            _ => None,
+        }
+    }
+}
+
+impl CssProperty for BorderRadius {
+    fn name(&self) -> &'static str {
+        "border-radius"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        true
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        let mut ans = Vec::new();
+
+        ans.extend(BorderTopLeftRadius::new().initial_value());
+        ans.extend(BorderTopRightRadius::new().initial_value());
+        ans.extend(BorderBottomRightRadius::new().initial_value());
+        ans.extend(BorderBottomLeftRadius::new().initial_value());
+
+        ans
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        let mut parts = value.split(|v| v == &Value::Slash);
+
+        let Some(horizontal) = parts.next().and_then(BorderRadius::expand) else {
+            return Vec::new();
+        };
+
+        let vertical = match parts.next() {
+            Some(vertical) => match BorderRadius::expand(vertical) {
+                Some(vertical) => vertical,
+                None => return Vec::new(),
+            },
+            None => horizontal.clone(),
+        };
+
+        if parts.next().is_some() {
+            return Vec::new();
+        }
+
+        let [top_left, top_right, bottom_right, bottom_left] = horizontal;
+        let [top_left_v, top_right_v, bottom_right_v, bottom_left_v] = vertical;
+
+        let mut ans = Vec::new();
+
+        ans.extend(BorderTopLeftRadius::new().maybe_new(&[top_left, top_left_v]));
+        ans.extend(BorderTopRightRadius::new().maybe_new(&[top_right, top_right_v]));
+        ans.extend(BorderBottomRightRadius::new().maybe_new(&[bottom_right, bottom_right_v]));
+        ans.extend(BorderBottomLeftRadius::new().maybe_new(&[bottom_left, bottom_left_v]));
+
+        ans
+    }
+}
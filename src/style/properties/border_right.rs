@@ -0,0 +1,88 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{
+    border_right_color::BorderRightColor, border_right_style::BorderRightStyle,
+    border_right_width::BorderRightWidth, CssProperty, Property,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BorderRight;
+
+impl BorderRight {
+    pub(crate) fn new() -> Self {
+        BorderRight
+    }
+}
+
+impl CssProperty for BorderRight {
+    fn name(&self) -> &'static str {
+        "border-right"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        true
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        let mut ans = Vec::new();
+
+        ans.extend(BorderRightWidth::new().initial_value());
+        ans.extend(BorderRightStyle::new().initial_value());
+        ans.extend(BorderRightColor::new().initial_value());
+
+        ans
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.is_empty() || value.len() > 3 {
+            return Vec::new();
+        }
+
+        let mut ans = Vec::new();
+        let (mut border_width_found, mut border_style_found, mut border_color_found) =
+            (false, false, false);
+
+        for v in value {
+            if Validations::border_width(v) {
+                if border_width_found {
+                    return Vec::new();
+                }
+
+                border_width_found = true;
+                ans.extend(BorderRightWidth::new().maybe_new(std::slice::from_ref(v)));
+            } else if Validations::border_style(v) {
+                if border_style_found {
+                    return Vec::new();
+                }
+
+                border_style_found = true;
+                ans.extend(BorderRightStyle::new().maybe_new(std::slice::from_ref(v)));
+            } else if Validations::color(v) {
+                if border_color_found {
+                    return Vec::new();
+                }
+
+                border_color_found = true;
+                ans.extend(BorderRightColor::new().maybe_new(std::slice::from_ref(v)));
+            } else {
+                return Vec::new();
+            }
+        }
+
+        if !border_width_found {
+            ans.extend(BorderRightWidth::new().initial_value());
+        }
+        if !border_style_found {
+            ans.extend(BorderRightStyle::new().initial_value());
+        }
+        if !border_color_found {
+            ans.extend(BorderRightColor::new().initial_value());
+        }
+
+        ans
+    }
+}
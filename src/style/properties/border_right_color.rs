@@ -0,0 +1,64 @@
+use crate::{
+    css::types::{Rgb, Value},
+    style::{utils::keyword_to_rgb, validations::Validations},
+};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BorderRightColor {
+    value: Value,
+}
+
+impl BorderRightColor {
+    pub(super) fn new() -> Self {
+        BorderRightColor {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self, color: &Rgb) -> Rgb {
+        match &self.value {
+            Value::Rgb(rgb) => rgb.clone(),
+            Value::Keyword(keyword) => keyword_to_rgb(keyword),
+            Value::NotDeclared => color.clone(),
+            _ => panic!("Unexpected value for border-right-color"),
+        }
+    }
+}
+
+impl CssProperty for BorderRightColor {
+    fn name(&self) -> &'static str {
+        "border-right-color"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BorderRightColor(BorderRightColor {
+            value: Value::NotDeclared,
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.len() != 1 {
+            return Vec::new();
+        }
+
+        let value = value.first().unwrap();
+
+        if Validations::color(value) {
+            return vec![Property::BorderRightColor(BorderRightColor {
+                value: value.clone(),
+            })];
+        }
+
+        Vec::new()
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BorderRightStyle {
+    value: Value,
+}
+
+impl BorderRightStyle {
+    pub(super) fn new() -> Self {
+        BorderRightStyle {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> &Value {
+        &self.value
+    }
+}
+
+impl CssProperty for BorderRightStyle {
+    fn name(&self) -> &'static str {
+        "border-right-style"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BorderRightStyle(BorderRightStyle {
+            value: Value::Keyword("none".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.len() != 1 {
+            return Vec::new();
+        }
+
+        let value = value.first().unwrap();
+
+        if Validations::border_style(value) {
+            return vec![Property::BorderRightStyle(BorderRightStyle {
+                value: value.clone(),
+            })];
+        }
+
+        Vec::new()
+    }
+}
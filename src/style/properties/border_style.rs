@@ -1,21 +1,30 @@
 use crate::{css::types::Value, style::validations::Validations};
 
-use super::{CssProperty, Property};
+use super::{
+    border_bottom_style::BorderBottomStyle, border_left_style::BorderLeftStyle,
+    border_right_style::BorderRightStyle, border_top_style::BorderTopStyle, CssProperty, Property,
+};
 
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) struct BorderStyle {
-    value: Value,
-}
+pub(crate) struct BorderStyle;
 
 impl BorderStyle {
     pub(super) fn new() -> Self {
-        BorderStyle {
-            value: Value::default(),
-        }
+        BorderStyle
     }
 
-    pub(crate) fn actual_value(&self) -> &Value {
-        &self.value
+    fn create_border_style_properties_with_values(
+        &self,
+        values: (Value, Value, Value, Value),
+    ) -> Vec<Property> {
+        let mut ans = Vec::new();
+
+        ans.extend(BorderTopStyle::new().maybe_new(&[values.0]));
+        ans.extend(BorderRightStyle::new().maybe_new(&[values.1]));
+        ans.extend(BorderBottomStyle::new().maybe_new(&[values.2]));
+        ans.extend(BorderLeftStyle::new().maybe_new(&[values.3]));
+
+        ans
     }
 }
 
@@ -29,28 +38,58 @@ impl CssProperty for BorderStyle {
     }
 
     fn is_shorthand(&self) -> bool {
-        false
+        true
     }
 
     fn initial_value(&self) -> Vec<Property> {
-        vec![Property::BorderStyle(BorderStyle {
-            value: Value::Keyword("none".to_string()),
-        })]
+        let mut ans = Vec::new();
+
+        ans.extend(BorderTopStyle::new().initial_value());
+        ans.extend(BorderRightStyle::new().initial_value());
+        ans.extend(BorderBottomStyle::new().initial_value());
+        ans.extend(BorderLeftStyle::new().initial_value());
+
+        ans
     }
 
     fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
-        if value.len() != 1 {
+        if value.is_empty() || value.len() > 4 {
             return Vec::new();
         }
 
-        let value = value.first().unwrap();
-
-        if Validations::border_style(value) {
-            return vec![Property::BorderStyle(BorderStyle {
-                value: value.clone(),
-            })];
+        for val in value {
+            if !Validations::border_style(val) {
+                return Vec::new();
+            }
         }
 
-        Vec::new()
+        let values = match value.len() {
+            1 => (
+                value[0].clone(),
+                value[0].clone(),
+                value[0].clone(),
+                value[0].clone(),
+            ),
+            2 => (
+                value[0].clone(),
+                value[1].clone(),
+                value[0].clone(),
+                value[1].clone(),
+            ),
+            3 => (
+                value[0].clone(),
+                value[1].clone(),
+                value[2].clone(),
+                value[1].clone(),
+            ),
+            _ => (
+                value[0].clone(),
+                value[1].clone(),
+                value[2].clone(),
+                value[3].clone(),
+            ),
+        };
+
+        self.create_border_style_properties_with_values(values)
     }
 }
@@ -0,0 +1,76 @@
+use crate::{
+    css::types::{Unit, Value},
+    style::validations::Validations,
+};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BorderTopRightRadius {
+    horizontal: Value,
+    vertical: Value,
+}
+
+impl BorderTopRightRadius {
+    pub(super) fn new() -> Self {
+        BorderTopRightRadius {
+            horizontal: Value::default(),
+            vertical: Value::default(),
+        }
+    }
+
+    /// Returns the horizontal and vertical radii, resolving percentages against the border box.
+    pub(crate) fn actual_value(&self, width: f32, height: f32) -> (f32, f32) {
+        let resolve = |value: &Value, reference: f32| match value {
+            Value::Dimension(value, _) => *value,
+            Value::Percentage(value) => reference * value / 100.,
+            _ => 0.,
+        };
+
+        (
+            resolve(&self.horizontal, width),
+            resolve(&self.vertical, height),
+        )
+    }
+}
+
+impl CssProperty for BorderTopRightRadius {
+    fn name(&self) -> &'static str {
+        "border-top-right-radius"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BorderTopRightRadius(BorderTopRightRadius {
+            horizontal: Value::Dimension(0., Unit::Px),
+            vertical: Value::Dimension(0., Unit::Px),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.is_empty() || value.len() > 2 {
+            return Vec::new();
+        }
+
+        for v in value {
+            if !Validations::non_negative_length(v) && !Validations::non_negative_percentage(v) {
+                return Vec::new();
+            }
+        }
+
+        let horizontal = value[0].clone();
+        let vertical = value.last().unwrap().clone();
+
+        vec![Property::BorderTopRightRadius(BorderTopRightRadius {
+            horizontal,
+            vertical,
+        })]
+    }
+}
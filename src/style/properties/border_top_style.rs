@@ -0,0 +1,56 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BorderTopStyle {
+    value: Value,
+}
+
+impl BorderTopStyle {
+    pub(super) fn new() -> Self {
+        BorderTopStyle {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> &Value {
+        &self.value
+    }
+}
+
+impl CssProperty for BorderTopStyle {
+    fn name(&self) -> &'static str {
+        "border-top-style"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BorderTopStyle(BorderTopStyle {
+            value: Value::Keyword("none".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.len() != 1 {
+            return Vec::new();
+        }
+
+        let value = value.first().unwrap();
+
+        if Validations::border_style(value) {
+            return vec![Property::BorderTopStyle(BorderTopStyle {
+                value: value.clone(),
+            })];
+        }
+
+        Vec::new()
+    }
+}
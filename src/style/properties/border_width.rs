@@ -1,34 +1,30 @@
 use crate::{css::types::Value, style::validations::Validations};
 
-use super::{CssProperty, Property};
+use super::{
+    border_bottom_width::BorderBottomWidth, border_left_width::BorderLeftWidth,
+    border_right_width::BorderRightWidth, border_top_width::BorderTopWidth, CssProperty, Property,
+};
 
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) struct BorderWidth {
-    value: Value,
-}
+pub(crate) struct BorderWidth;
 
 impl BorderWidth {
     pub(super) fn new() -> Self {
-        BorderWidth {
-            value: Value::default(),
-        }
+        BorderWidth
     }
 
-    pub(crate) fn actual_value(&self, border_style: &Value) -> f32 {
-        if matches!(border_style, Value::Keyword(value) if value == "none" || value == "hidden") {
-            return 0.;
-        }
+    fn create_border_width_properties_with_values(
+        &self,
+        values: (Value, Value, Value, Value),
+    ) -> Vec<Property> {
+        let mut ans = Vec::new();
 
-        match &self.value {
-            Value::Keyword(value) => match value.as_str() {
-                "thin" => 1.,
-                "medium" => 3.,
-                "thick" => 5.,
-                _ => panic!("Invalid border-width value"),
-            },
-            Value::Dimension(value, _) => *value,
-            _ => panic!("Invalid border-width value"),
-        }
+        ans.extend(BorderTopWidth::new().maybe_new(&[values.0]));
+        ans.extend(BorderRightWidth::new().maybe_new(&[values.1]));
+        ans.extend(BorderBottomWidth::new().maybe_new(&[values.2]));
+        ans.extend(BorderLeftWidth::new().maybe_new(&[values.3]));
+
+        ans
     }
 }
 
@@ -42,28 +38,58 @@ impl CssProperty for BorderWidth {
     }
 
     fn is_shorthand(&self) -> bool {
-        false
+        true
     }
 
     fn initial_value(&self) -> Vec<Property> {
-        vec![Property::BorderWidth(BorderWidth {
-            value: Value::Keyword("medium".to_string()),
-        })]
+        let mut ans = Vec::new();
+
+        ans.extend(BorderTopWidth::new().initial_value());
+        ans.extend(BorderRightWidth::new().initial_value());
+        ans.extend(BorderBottomWidth::new().initial_value());
+        ans.extend(BorderLeftWidth::new().initial_value());
+
+        ans
     }
 
     fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
-        if value.len() != 1 {
+        if value.is_empty() || value.len() > 4 {
             return Vec::new();
         }
 
-        let value = value.first().unwrap();
-
-        if Validations::border_width(value) {
-            return vec![Property::BorderWidth(BorderWidth {
-                value: value.clone(),
-            })];
+        for val in value {
+            if !Validations::border_width(val) {
+                return Vec::new();
+            }
         }
 
-        Vec::new()
+        let values = match value.len() {
+            1 => (
+                value[0].clone(),
+                value[0].clone(),
+                value[0].clone(),
+                value[0].clone(),
+            ),
+            2 => (
+                value[0].clone(),
+                value[1].clone(),
+                value[0].clone(),
+                value[1].clone(),
+            ),
+            3 => (
+                value[0].clone(),
+                value[1].clone(),
+                value[2].clone(),
+                value[1].clone(),
+            ),
+            _ => (
+                value[0].clone(),
+                value[1].clone(),
+                value[2].clone(),
+                value[3].clone(),
+            ),
+        };
+
+        self.create_border_width_properties_with_values(values)
     }
 }
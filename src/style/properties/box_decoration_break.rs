@@ -0,0 +1,66 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BoxDecorationBreakMode {
+    /// The fragments of the box are drawn as if the box were cut where it breaks.
+    Slice,
+    /// Each fragment of the box has its own padding, borders and margins on every side.
+    Clone,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BoxDecorationBreak {
+    value: Value,
+}
+
+impl BoxDecorationBreak {
+    pub(super) fn new() -> Self {
+        BoxDecorationBreak {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> BoxDecorationBreakMode {
+        match &self.value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "slice" => BoxDecorationBreakMode::Slice,
+                "clone" => BoxDecorationBreakMode::Clone,
+                _ => panic!("Invalid box-decoration-break value"),
+            },
+            _ => panic!("Invalid box-decoration-break value"),
+        }
+    }
+}
+
+impl CssProperty for BoxDecorationBreak {
+    fn name(&self) -> &'static str {
+        "box-decoration-break"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BoxDecorationBreak(BoxDecorationBreak {
+            value: Value::Keyword("slice".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value] if Validations::keyword(value, &["slice", "clone"]) => {
+                vec![Property::BoxDecorationBreak(BoxDecorationBreak {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
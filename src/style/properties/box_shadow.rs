@@ -0,0 +1,128 @@
+use crate::{
+    css::types::{Rgb, Value},
+    style::{utils::keyword_to_rgb, validations::Validations},
+};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Shadow {
+    pub(crate) offset_x: f32,
+    pub(crate) offset_y: f32,
+    pub(crate) blur: f32,
+    pub(crate) spread: f32,
+    pub(crate) inset: bool,
+    color: Value,
+}
+
+impl Shadow {
+    /// Returns the color of the shadow, which defaults to `currentColor`.
+    pub(crate) fn color(&self, color: &Rgb) -> Rgb {
+        match &self.color {
+            Value::Rgb(rgb) => rgb.clone(),
+            Value::Keyword(keyword) => keyword_to_rgb(keyword),
+            _ => color.clone(),
+        }
+    }
+
+    /// Parses a comma-separated list of shadows, as used by `box-shadow` and, without spread
+    /// and `inset`, by `text-shadow`.
+    pub(super) fn parse_list(value: &[Value], allow_spread_and_inset: bool) -> Option<Vec<Self>> {
+        if let [Value::Keyword(keyword)] = value {
+            return (keyword == "none").then(Vec::new);
+        }
+
+        value
+            .split(|v| v == &Value::Comma)
+            .map(|shadow| Shadow::parse(shadow, allow_spread_and_inset))
+            .collect()
+    }
+
+    fn parse(value: &[Value], allow_spread_and_inset: bool) -> Option<Self> {
+        let mut lengths = Vec::new();
+        let mut lengths_end = None;
+        let mut color = None;
+        let mut inset = false;
+
+        for (i, v) in value.iter().enumerate() {
+            match v {
+                Value::Dimension(length, _) if Validations::length(v) => {
+                    // The lengths of a shadow must be contiguous.
+                    if lengths_end.is_some_and(|end| end != i) {
+                        return None;
+                    }
+
+                    lengths.push(*length);
+                    lengths_end = Some(i + 1);
+                }
+                _ if Validations::color(v) && color.is_none() => color = Some(v.clone()),
+                _ if allow_spread_and_inset && !inset && Validations::keyword(v, &["inset"]) => {
+                    inset = true
+                }
+                _ => return None,
+            }
+        }
+
+        let max_lengths = if allow_spread_and_inset { 4 } else { 3 };
+
+        if lengths.len() < 2 || lengths.len() > max_lengths {
+            return None;
+        }
+
+        let blur = lengths.get(2).copied().unwrap_or(0.);
+
+        if blur < 0. {
+            return None;
+        }
+
+        Some(Shadow {
+            offset_x: lengths[0],
+            offset_y: lengths[1],
+            blur,
+            spread: lengths.get(3).copied().unwrap_or(0.),
+            inset,
+            color: color.unwrap_or(Value::NotDeclared),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BoxShadow {
+    value: Vec<Shadow>,
+}
+
+impl BoxShadow {
+    pub(super) fn new() -> Self {
+        BoxShadow { value: Vec::new() }
+    }
+
+    pub(crate) fn actual_value(&self) -> &[Shadow] {
+        &self.value
+    }
+}
+
+impl CssProperty for BoxShadow {
+    fn name(&self) -> &'static str {
+        "box-shadow"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::BoxShadow(BoxShadow { value: Vec::new() })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        let Some(shadows) = Shadow::parse_list(value, true) else {
+            return Vec::new();
+        };
+
+        vec![Property::BoxShadow(BoxShadow { value: shadows })]
+    }
+}
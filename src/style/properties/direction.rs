@@ -0,0 +1,64 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TextDirection {
+    Ltr,
+    Rtl,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Direction {
+    value: Value,
+}
+
+impl Direction {
+    pub(super) fn new() -> Self {
+        Direction {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> TextDirection {
+        match &self.value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "ltr" => TextDirection::Ltr,
+                "rtl" => TextDirection::Rtl,
+                _ => panic!("Invalid direction value"),
+            },
+            _ => panic!("Invalid direction value"),
+        }
+    }
+}
+
+impl CssProperty for Direction {
+    fn name(&self) -> &'static str {
+        "direction"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::Direction(Direction {
+            value: Value::Keyword("ltr".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value] if Validations::keyword(value, &["ltr", "rtl"]) => {
+                vec![Property::Direction(Direction {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
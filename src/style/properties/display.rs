@@ -45,7 +45,7 @@ impl CssProperty for Display {
 
         let value = value.first().unwrap();
 
-        if Validations::keyword(value, &["inline", "block", "none"]) {
+        if Validations::keyword(value, &["inline", "block", "inline-block", "none"]) {
             return vec![Property::Display(Display {
                 value: value.clone(),
             })];
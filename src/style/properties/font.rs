@@ -0,0 +1,133 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{
+    font_family::FontFamily, font_size::FontSize, font_style::FontStyle, font_weight::FontWeight,
+    line_height::LineHeight, CssProperty, Property,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Font;
+
+impl Font {
+    pub(crate) fn new() -> Self {
+        Font
+    }
+}
+
+impl CssProperty for Font {
+    fn name(&self) -> &'static str {
+        "font"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        true
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        let mut ans = Vec::new();
+
+        ans.extend(FontStyle::new().initial_value());
+        ans.extend(FontWeight::new().initial_value());
+        ans.extend(FontSize::new().initial_value());
+        ans.extend(LineHeight::new().initial_value());
+        ans.extend(FontFamily::new().initial_value());
+
+        ans
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        let mut ans = Vec::new();
+        let (mut font_style_found, mut font_weight_found) = (false, false);
+        let mut i = 0;
+
+        // Optional font-style and font-weight, in any order, before the mandatory font-size
+        let font_size = loop {
+            let Some(v) = value.get(i) else {
+                return Vec::new();
+            };
+
+            i += 1;
+
+            if Validations::keyword(v, &["normal"]) {
+                continue;
+            }
+
+            if Validations::keyword(v, &["italic", "oblique"]) {
+                if font_style_found {
+                    return Vec::new();
+                }
+
+                let mut font_style = vec![v.clone()];
+
+                if Validations::keyword(v, &["oblique"])
+                    && value.get(i).is_some_and(Validations::angle)
+                {
+                    font_style.push(value[i].clone());
+                    i += 1;
+                }
+
+                let font_style = FontStyle::new().maybe_new(&font_style);
+
+                if font_style.is_empty() {
+                    return Vec::new();
+                }
+
+                font_style_found = true;
+                ans.extend(font_style);
+            } else if Validations::font_weight(v) {
+                if font_weight_found {
+                    return Vec::new();
+                }
+
+                font_weight_found = true;
+                ans.extend(FontWeight::new().maybe_new(std::slice::from_ref(v)));
+            } else {
+                break FontSize::new().maybe_new(std::slice::from_ref(v));
+            }
+        };
+
+        if font_size.is_empty() {
+            return Vec::new();
+        }
+
+        ans.extend(font_size);
+
+        if value.get(i) == Some(&Value::Slash) {
+            let Some(v) = value.get(i + 1) else {
+                return Vec::new();
+            };
+
+            let line_height = LineHeight::new().maybe_new(std::slice::from_ref(v));
+
+            if line_height.is_empty() {
+                return Vec::new();
+            }
+
+            ans.extend(line_height);
+            i += 2;
+        } else {
+            ans.extend(LineHeight::new().initial_value());
+        }
+
+        let font_family = FontFamily::new().maybe_new(&value[i..]);
+
+        if font_family.is_empty() {
+            return Vec::new();
+        }
+
+        ans.extend(font_family);
+
+        if !font_style_found {
+            ans.extend(FontStyle::new().initial_value());
+        }
+        if !font_weight_found {
+            ans.extend(FontWeight::new().initial_value());
+        }
+
+        ans
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FontFamily {
+    value: Vec<String>,
+}
+
+impl FontFamily {
+    pub(super) fn new() -> Self {
+        FontFamily { value: Vec::new() }
+    }
+
+    pub(crate) fn actual_value(&self) -> &[String] {
+        &self.value
+    }
+
+    fn parse_family(value: &[Value]) -> Option<String> {
+        match value {
+            [Value::String(name)] => Some(name.clone()),
+            [Value::Keyword(keyword)] if Validations::generic_family(&value[0]) => {
+                Some(keyword.clone())
+            }
+            [] => None,
+            _ => {
+                let mut names = Vec::new();
+
+                for v in value {
+                    let Value::Keyword(name) = v else {
+                        return None;
+                    };
+
+                    names.push(name.as_str());
+                }
+
+                Some(names.join(" "))
+            }
+        }
+    }
+}
+
+impl CssProperty for FontFamily {
+    fn name(&self) -> &'static str {
+        "font-family"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::FontFamily(FontFamily {
+            value: vec!["sans-serif".to_string()],
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        let mut families = Vec::new();
+
+        for family in value.split(|v| v == &Value::Comma) {
+            let Some(family) = FontFamily::parse_family(family) else {
+                return Vec::new();
+            };
+
+            families.push(family);
+        }
+
+        vec![Property::FontFamily(FontFamily { value: families })]
+    }
+}
@@ -23,6 +23,19 @@ impl FontSize {
             _ => panic!("Invalid font-size value"),
         }
     }
+
+    /// `smaller` and `larger` scale the parent size by the ratio between keyword sizes.
+    pub(crate) fn computed_value(&self, parent_font_size: f32) -> FontSize {
+        let value = match &self.value {
+            Value::Keyword(keyword) if keyword == "smaller" => parent_font_size / 1.2,
+            Value::Keyword(keyword) if keyword == "larger" => parent_font_size * 1.2,
+            _ => return self.clone(),
+        };
+
+        FontSize {
+            value: Value::Dimension(value, Unit::Px),
+        }
+    }
 }
 
 impl CssProperty for FontSize {
@@ -51,7 +64,9 @@ impl CssProperty for FontSize {
 
         let value = value.first().unwrap();
 
-        if Validations::length(value) {
+        if Validations::non_negative_length(value)
+            || Validations::keyword(value, &["smaller", "larger"])
+        {
             return vec![Property::FontSize(FontSize {
                 value: value.clone(),
             })];
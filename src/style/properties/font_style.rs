@@ -0,0 +1,93 @@
+use font_kit::properties::Style;
+
+use crate::{
+    css::types::{Unit, Value},
+    style::validations::Validations,
+};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FontStyle {
+    value: Value,
+    angle: Value,
+}
+
+impl FontStyle {
+    pub(super) fn new() -> Self {
+        FontStyle {
+            value: Value::default(),
+            angle: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> Style {
+        match &self.value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "normal" => Style::Normal,
+                "italic" => Style::Italic,
+                "oblique" => match &self.angle {
+                    Value::Dimension(angle, _) if *angle == 0. => Style::Normal,
+                    _ => Style::Oblique,
+                },
+                _ => panic!("Invalid font-style value"),
+            },
+            _ => panic!("Invalid font-style value"),
+        }
+    }
+}
+
+impl CssProperty for FontStyle {
+    fn name(&self) -> &'static str {
+        "font-style"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::FontStyle(FontStyle {
+            value: Value::Keyword("normal".to_string()),
+            angle: Value::Dimension(0., Unit::Deg),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value] if Validations::keyword(value, &["normal", "italic"]) => {
+                vec![Property::FontStyle(FontStyle {
+                    value: value.clone(),
+                    angle: Value::Dimension(0., Unit::Deg),
+                })]
+            }
+            [value] if Validations::keyword(value, &["oblique"]) => {
+                vec![Property::FontStyle(FontStyle {
+                    value: value.clone(),
+                    angle: Value::Dimension(14., Unit::Deg),
+                })]
+            }
+            [value, angle]
+                if Validations::keyword(value, &["oblique"]) && Validations::angle(angle) =>
+            {
+                let Value::Dimension(degrees, _) = angle else {
+                    return Vec::new();
+                };
+
+                if !(-90. ..=90.).contains(degrees) {
+                    return Vec::new();
+                }
+
+                vec![Property::FontStyle(FontStyle {
+                    value: value.clone(),
+                    angle: angle.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -61,8 +61,8 @@ impl CssProperty for Height {
 
         let value = value.first().unwrap();
 
-        if Validations::length(value)
-            || Validations::percentage(value)
+        if Validations::non_negative_length(value)
+            || Validations::non_negative_percentage(value)
             || Validations::keyword(value, &["auto"])
         {
             return vec![Property::Height(Height {
@@ -0,0 +1,66 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum HyphensMode {
+    None,
+    Manual,
+    Auto,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Hyphens {
+    value: Value,
+}
+
+impl Hyphens {
+    pub(super) fn new() -> Self {
+        Hyphens {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> HyphensMode {
+        match &self.value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "none" => HyphensMode::None,
+                "manual" => HyphensMode::Manual,
+                "auto" => HyphensMode::Auto,
+                _ => panic!("Invalid hyphens value"),
+            },
+            _ => panic!("Invalid hyphens value"),
+        }
+    }
+}
+
+impl CssProperty for Hyphens {
+    fn name(&self) -> &'static str {
+        "hyphens"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::Hyphens(Hyphens {
+            value: Value::Keyword("manual".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value] if Validations::keyword(value, &["none", "manual", "auto"]) => {
+                vec![Property::Hyphens(Hyphens {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
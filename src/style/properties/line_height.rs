@@ -17,12 +17,41 @@ impl LineHeight {
         }
     }
 
-    pub(crate) fn actual_value(&self) -> f32 {
+    /// Returns the line height in pixels, given the font size of the element and the line
+    /// height the font asks for, used by `normal`. Numbers scale the font size.
+    pub(crate) fn actual_value(&self, font_size: f32, normal: f32) -> f32 {
         match &self.value {
-            Value::Dimension(value, Unit::None) => *value,
+            Value::Keyword(keyword) if keyword == "normal" => normal,
+            Value::Dimension(value, Unit::None) => font_size * value,
+            Value::Dimension(value, _) => *value,
+            Value::Percentage(percentage) => font_size * percentage / 100.,
             _ => panic!("Invalid line-height value"),
         }
     }
+
+    /// Resolves percentages of the font size of the element into pixels, so descendants
+    /// inherit the line height of the element rather than the percentage. Numbers stay as
+    /// they are, scaling the font size of each descendant.
+    pub(crate) fn computed_value(&self, font_size: f32) -> LineHeight {
+        match &self.value {
+            Value::Percentage(percentage) => LineHeight {
+                value: Value::Dimension(font_size * percentage / 100., Unit::Px),
+            },
+            _ => self.clone(),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::Dimension(value, _) | Value::Percentage(value) if *value < 0. => false,
+            _ => {
+                Validations::keyword(value, &["normal"])
+                    || Validations::number(value)
+                    || Validations::length(value)
+                    || Validations::percentage(value)
+            }
+        }
+    }
 }
 
 impl CssProperty for LineHeight {
@@ -40,23 +69,16 @@ impl CssProperty for LineHeight {
 
     fn initial_value(&self) -> Vec<Property> {
         vec![Property::LineHeight(LineHeight {
-            value: Value::Dimension(1.2, Unit::None),
+            value: Value::Keyword("normal".to_string()),
         })]
     }
 
     fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
-        if value.len() != 1 {
-            return Vec::new();
-        }
-
-        let value = value.first().unwrap();
-
-        if Validations::number(value) {
-            return vec![Property::LineHeight(LineHeight {
+        match value {
+            [value] if LineHeight::is_valid(value) => vec![Property::LineHeight(LineHeight {
                 value: value.clone(),
-            })];
+            })],
+            _ => Vec::new(),
         }
-
-        Vec::new()
     }
 }
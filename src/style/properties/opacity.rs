@@ -0,0 +1,64 @@
+use crate::{
+    css::types::{Unit, Value},
+    style::validations::Validations,
+};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Opacity {
+    value: Value,
+}
+
+impl Opacity {
+    pub(super) fn new() -> Self {
+        Opacity {
+            value: Value::default(),
+        }
+    }
+
+    /// Returns the opacity clamped to the `[0, 1]` range.
+    pub(crate) fn actual_value(&self) -> f32 {
+        match &self.value {
+            Value::Dimension(value, Unit::None) => value.clamp(0., 1.),
+            Value::Percentage(value) => (value / 100.).clamp(0., 1.),
+            _ => panic!("Invalid opacity value"),
+        }
+    }
+}
+
+impl CssProperty for Opacity {
+    fn name(&self) -> &'static str {
+        "opacity"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::Opacity(Opacity {
+            value: Value::Dimension(1., Unit::None),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.len() != 1 {
+            return Vec::new();
+        }
+
+        let value = value.first().unwrap();
+
+        if Validations::number(value) || Validations::percentage(value) {
+            return vec![Property::Opacity(Opacity {
+                value: value.clone(),
+            })];
+        }
+
+        Vec::new()
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum OverflowWrapMode {
+    Normal,
+    BreakWord,
+    Anywhere,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OverflowWrap {
+    value: Value,
+}
+
+impl OverflowWrap {
+    pub(super) fn new() -> Self {
+        OverflowWrap {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> OverflowWrapMode {
+        match &self.value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "normal" => OverflowWrapMode::Normal,
+                "break-word" => OverflowWrapMode::BreakWord,
+                "anywhere" => OverflowWrapMode::Anywhere,
+                _ => panic!("Invalid overflow-wrap value"),
+            },
+            _ => panic!("Invalid overflow-wrap value"),
+        }
+    }
+}
+
+impl CssProperty for OverflowWrap {
+    fn name(&self) -> &'static str {
+        "overflow-wrap"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::OverflowWrap(OverflowWrap {
+            value: Value::Keyword("normal".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value] if Validations::keyword(value, &["normal", "break-word", "anywhere"]) => {
+                vec![Property::OverflowWrap(OverflowWrap {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    css::types::{Unit, Value},
+    style::validations::Validations,
+};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TabSize {
+    value: Value,
+}
+
+impl TabSize {
+    pub(super) fn new() -> Self {
+        TabSize {
+            value: Value::default(),
+        }
+    }
+
+    /// Returns the distance between tab stops. Numbers count the advance of a space.
+    pub(crate) fn actual_value(&self, space_width: f32) -> f32 {
+        match &self.value {
+            Value::Dimension(count, Unit::None) => count * space_width,
+            Value::Dimension(length, _) => *length,
+            _ => panic!("Invalid tab-size value"),
+        }
+    }
+}
+
+impl CssProperty for TabSize {
+    fn name(&self) -> &'static str {
+        "tab-size"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::TabSize(TabSize {
+            value: Value::Dimension(8., Unit::None),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [Value::Dimension(size, _)] if *size < 0. => Vec::new(),
+            [value] if Validations::number(value) || Validations::length(value) => {
+                vec![Property::TabSize(TabSize {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
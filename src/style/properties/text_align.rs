@@ -1,7 +1,7 @@
 use crate::css::types::Value;
 use crate::layout::box_types::BoxType;
 use crate::layout::layout_node::LayoutNode;
-use crate::style::properties::{CssProperty, Property};
+use crate::style::properties::{direction::TextDirection, CssProperty, Property};
 use crate::style::validations::Validations;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -16,15 +16,21 @@ impl TextAlign {
         }
     }
 
-    pub(crate) fn apply(&self, node: &mut LayoutNode) {
+    /// Aligns the lines of the node, resolving `start` and `end` with the direction of its
+    /// text. Lines are filled from the left, so aligning them to the left is a no-op.
+    pub(crate) fn apply(&self, node: &mut LayoutNode, direction: TextDirection) {
         let Value::Keyword(keyword) = &self.value else {
             return;
         };
 
+        let rtl = direction == TextDirection::Rtl;
+
         match keyword.as_str() {
             "center" => self.apply_text_align_center(node),
             "right" => self.apply_text_align_right(node),
-            "justify" => self.apply_text_align_justify(node),
+            "start" if rtl => self.apply_text_align_right(node),
+            "end" if !rtl => self.apply_text_align_right(node),
+            "justify" => self.apply_text_align_justify(node, rtl),
             _ => {}
         }
     }
@@ -35,70 +41,70 @@ impl TextAlign {
             let remaining_space = node.box_dimensions.content.width - line_width;
 
             for word in &mut line.children {
-                word.box_dimensions.content.x += remaining_space / 2.0;
+                word.translate(remaining_space / 2.0, 0.);
             }
         }
     }
 
     fn apply_text_align_right(&self, node: &mut LayoutNode) {
-        for line in &mut node.children {
-            let line_width = line.box_dimensions.content.width;
-            let remaining_space = node.box_dimensions.content.width - line_width;
+        let width = node.box_dimensions.content.width;
 
-            for word in &mut line.children {
-                word.box_dimensions.content.x += remaining_space;
-            }
+        for line in &mut node.children {
+            self.align_line_right(line, width);
         }
     }
 
-    fn apply_text_align_justify(&self, node: &mut LayoutNode) {
-        let length = node.children.len();
+    fn align_line_right(&self, line: &mut LayoutNode, width: f32) {
+        let remaining_space = width - line.box_dimensions.content.width;
 
-        if length <= 1 {
-            return;
+        for word in &mut line.children {
+            word.translate(remaining_space, 0.);
         }
+    }
 
-        let lines_except_last = &mut node.children[..length - 1];
-
-        for line in lines_except_last {
-            let line_width = line.box_dimensions.content.width;
-            let remaining_space =
-                node.box_dimensions.content.width + self.count_whitespace_length(line) - line_width;
-
-            line.children.retain(|word| {
-                if let BoxType::Word(word) = &word.box_type {
-                    word.text != " "
-                } else {
-                    false
-                }
-            });
-
-            if line.children.len() <= 1 {
-                continue;
-            }
+    fn apply_text_align_justify(&self, node: &mut LayoutNode, rtl: bool) {
+        let width = node.box_dimensions.content.width;
+        let length = node.children.len();
 
-            let space = remaining_space / (line.children.len() - 1) as f32;
-            let mut acc_x = 0.;
+        for (i, line) in node.children.iter_mut().enumerate() {
+            // The last line and lines ended by a preserved line break are aligned to the start.
+            let is_last = i + 1 == length
+                || line
+                    .children
+                    .iter()
+                    .any(|word| matches!(&word.box_type, BoxType::Word(word) if word.text == "\n"));
 
-            for word in &mut line.children {
-                word.box_dimensions.content.x = line.box_dimensions.content.x + acc_x;
-                acc_x += word.box_dimensions.content.width + space;
+            if (is_last || !self.justify_line(line, width)) && rtl {
+                self.align_line_right(line, width);
             }
         }
     }
 
-    fn count_whitespace_length(&self, line: &LayoutNode) -> f32 {
-        let mut count = 0.0;
+    /// Returns whether the line had spaces to widen.
+    fn justify_line(&self, line: &mut LayoutNode, width: f32) -> bool {
+        let is_space =
+            |word: &LayoutNode| matches!(&word.box_type, BoxType::Word(word) if word.text == " ");
+        let spaces = line.children.iter().filter(|word| is_space(word)).count();
+
+        if spaces == 0 {
+            return false;
+        }
+
+        let space = (width - line.box_dimensions.content.width) / spaces as f32;
+        let mut acc_x = 0.;
+
+        for word in &mut line.children {
+            word.translate(acc_x, 0.);
 
-        for word in &line.children {
-            if let BoxType::Word(word_box) = &word.box_type {
-                if word_box.text == " " {
-                    count += word.box_dimensions.content.width;
-                }
+            if is_space(word) {
+                word.box_dimensions.content.width += space;
+                acc_x += space;
             }
         }
 
-        count
+        line.box_dimensions.content.width = width;
+
+        true
     }
 }
 
@@ -117,7 +123,7 @@ impl CssProperty for TextAlign {
 
     fn initial_value(&self) -> Vec<Property> {
         vec![Property::TextAlign(TextAlign {
-            value: Value::Keyword("left".to_string()),
+            value: Value::Keyword("start".to_string()),
         })]
     }
 
@@ -128,7 +134,10 @@ impl CssProperty for TextAlign {
 
         let value = value.first().unwrap();
 
-        if Validations::keyword(value, &["left", "center", "right", "justify"]) {
+        if Validations::keyword(
+            value,
+            &["start", "end", "left", "center", "right", "justify"],
+        ) {
             return vec![Property::TextAlign(TextAlign {
                 value: value.clone(),
             })];
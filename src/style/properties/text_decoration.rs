@@ -0,0 +1,115 @@
+use crate::{
+    css::types::{Rgb, Value},
+    style::validations::Validations,
+};
+
+use super::{
+    text_decoration_color::TextDecorationColor,
+    text_decoration_line::{DecorationLine, TextDecorationLine},
+    text_decoration_style::{DecorationStyle, TextDecorationStyle},
+    text_decoration_thickness::TextDecorationThickness,
+    CssProperty, Property,
+};
+
+/// A line drawn over the text of an element and of its in-flow descendants, with the values
+/// of the element that established it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Decoration {
+    pub(crate) line: DecorationLine,
+    pub(crate) style: DecorationStyle,
+    pub(crate) color: Rgb,
+    /// The thickness in pixels, or `None` when it comes from the font of the text.
+    pub(crate) thickness: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TextDecoration;
+
+impl TextDecoration {
+    pub(crate) fn new() -> Self {
+        TextDecoration
+    }
+
+    /// Builds a longhand from its value in the shorthand, or from its initial value when the
+    /// shorthand does not specify it.
+    fn longhand(property: &dyn CssProperty, value: Option<&Value>) -> Vec<Property> {
+        match value {
+            Some(value) => property.maybe_new(std::slice::from_ref(value)),
+            None => property.initial_value(),
+        }
+    }
+}
+
+impl CssProperty for TextDecoration {
+    fn name(&self) -> &'static str {
+        "text-decoration"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        true
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        let mut ans = Vec::new();
+
+        ans.extend(TextDecorationLine::new().initial_value());
+        ans.extend(TextDecorationStyle::new().initial_value());
+        ans.extend(TextDecorationColor::new().initial_value());
+        ans.extend(TextDecorationThickness::new().initial_value());
+
+        ans
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if value.is_empty() {
+            return Vec::new();
+        }
+
+        let mut lines = Vec::new();
+        let (mut style, mut color, mut thickness) = (None, None, None);
+
+        for v in value {
+            if Validations::keyword(
+                v,
+                &["none", "underline", "overline", "line-through", "blink"],
+            ) {
+                lines.push(v.clone());
+            } else if TextDecorationStyle::is_valid(v) && style.is_none() {
+                style = Some(v);
+            } else if (Validations::color(v) || Validations::keyword(v, &["currentcolor"]))
+                && color.is_none()
+            {
+                color = Some(v);
+            } else if TextDecorationThickness::is_valid(v) && thickness.is_none() {
+                thickness = Some(v);
+            } else {
+                return Vec::new();
+            }
+        }
+
+        if !lines.is_empty() && !TextDecorationLine::is_valid(&lines) {
+            return Vec::new();
+        }
+
+        let mut ans = Vec::new();
+
+        if lines.is_empty() {
+            ans.extend(TextDecorationLine::new().initial_value());
+        } else {
+            ans.extend(TextDecorationLine::new().maybe_new(&lines));
+        }
+
+        ans.extend(TextDecoration::longhand(&TextDecorationStyle::new(), style));
+        ans.extend(TextDecoration::longhand(&TextDecorationColor::new(), color));
+        ans.extend(TextDecoration::longhand(
+            &TextDecorationThickness::new(),
+            thickness,
+        ));
+
+        ans
+    }
+}
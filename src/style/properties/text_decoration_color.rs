@@ -0,0 +1,60 @@
+use crate::{
+    css::types::{Rgb, Value},
+    style::{utils::keyword_to_rgb, validations::Validations},
+};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TextDecorationColor {
+    value: Value,
+}
+
+impl TextDecorationColor {
+    pub(super) fn new() -> Self {
+        TextDecorationColor {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self, color: &Rgb) -> Rgb {
+        match &self.value {
+            Value::Rgb(rgb) => rgb.clone(),
+            Value::Keyword(keyword) => keyword_to_rgb(keyword),
+            Value::NotDeclared => color.clone(),
+            _ => panic!("Unexpected value for text-decoration-color"),
+        }
+    }
+}
+
+impl CssProperty for TextDecorationColor {
+    fn name(&self) -> &'static str {
+        "text-decoration-color"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::TextDecorationColor(TextDecorationColor {
+            value: Value::NotDeclared,
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value] if Validations::keyword(value, &["currentcolor"]) => self.initial_value(),
+            [value] if Validations::color(value) => {
+                vec![Property::TextDecorationColor(TextDecorationColor {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DecorationLine {
+    Underline,
+    Overline,
+    LineThrough,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TextDecorationLine {
+    value: Vec<Value>,
+}
+
+impl TextDecorationLine {
+    pub(super) fn new() -> Self {
+        TextDecorationLine { value: Vec::new() }
+    }
+
+    /// Returns the lines drawn by the element. `blink` is accepted but never drawn.
+    pub(crate) fn actual_value(&self) -> Vec<DecorationLine> {
+        self.value
+            .iter()
+            .filter_map(|value| match value {
+                Value::Keyword(keyword) => match keyword.as_str() {
+                    "underline" => Some(DecorationLine::Underline),
+                    "overline" => Some(DecorationLine::Overline),
+                    "line-through" => Some(DecorationLine::LineThrough),
+                    "none" | "blink" => None,
+                    _ => panic!("Invalid text-decoration-line value"),
+                },
+                _ => panic!("Invalid text-decoration-line value"),
+            })
+            .collect()
+    }
+
+    pub(super) fn is_valid(value: &[Value]) -> bool {
+        match value {
+            [value] if Validations::keyword(value, &["none"]) => true,
+            [] => false,
+            _ => {
+                value.iter().all(|v| {
+                    Validations::keyword(v, &["underline", "overline", "line-through", "blink"])
+                }) && value
+                    .iter()
+                    .enumerate()
+                    .all(|(i, v)| !value[..i].contains(v))
+            }
+        }
+    }
+}
+
+impl CssProperty for TextDecorationLine {
+    fn name(&self) -> &'static str {
+        "text-decoration-line"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::TextDecorationLine(TextDecorationLine {
+            value: vec![Value::Keyword("none".to_string())],
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if !TextDecorationLine::is_valid(value) {
+            return Vec::new();
+        }
+
+        vec![Property::TextDecorationLine(TextDecorationLine {
+            value: value.to_vec(),
+        })]
+    }
+}
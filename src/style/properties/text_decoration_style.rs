@@ -0,0 +1,74 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DecorationStyle {
+    Solid,
+    Double,
+    Dotted,
+    Dashed,
+    Wavy,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TextDecorationStyle {
+    value: Value,
+}
+
+impl TextDecorationStyle {
+    pub(super) fn new() -> Self {
+        TextDecorationStyle {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> DecorationStyle {
+        match &self.value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "solid" => DecorationStyle::Solid,
+                "double" => DecorationStyle::Double,
+                "dotted" => DecorationStyle::Dotted,
+                "dashed" => DecorationStyle::Dashed,
+                "wavy" => DecorationStyle::Wavy,
+                _ => panic!("Invalid text-decoration-style value"),
+            },
+            _ => panic!("Invalid text-decoration-style value"),
+        }
+    }
+
+    pub(super) fn is_valid(value: &Value) -> bool {
+        Validations::keyword(value, &["solid", "double", "dotted", "dashed", "wavy"])
+    }
+}
+
+impl CssProperty for TextDecorationStyle {
+    fn name(&self) -> &'static str {
+        "text-decoration-style"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::TextDecorationStyle(TextDecorationStyle {
+            value: Value::Keyword("solid".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value] if TextDecorationStyle::is_valid(value) => {
+                vec![Property::TextDecorationStyle(TextDecorationStyle {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
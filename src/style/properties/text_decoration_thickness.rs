@@ -0,0 +1,64 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TextDecorationThickness {
+    value: Value,
+}
+
+impl TextDecorationThickness {
+    pub(super) fn new() -> Self {
+        TextDecorationThickness {
+            value: Value::default(),
+        }
+    }
+
+    /// Returns the thickness in pixels, or `None` when it comes from the font. Percentages
+    /// refer to the font size of the element.
+    pub(crate) fn actual_value(&self, font_size: f32) -> Option<f32> {
+        match &self.value {
+            Value::Keyword(keyword) if keyword == "auto" || keyword == "from-font" => None,
+            Value::Dimension(thickness, _) => Some(*thickness),
+            Value::Percentage(percentage) => Some(font_size * percentage / 100.),
+            _ => panic!("Invalid text-decoration-thickness value"),
+        }
+    }
+
+    pub(super) fn is_valid(value: &Value) -> bool {
+        Validations::keyword(value, &["auto", "from-font"])
+            || Validations::length(value)
+            || Validations::percentage(value)
+    }
+}
+
+impl CssProperty for TextDecorationThickness {
+    fn name(&self) -> &'static str {
+        "text-decoration-thickness"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::TextDecorationThickness(TextDecorationThickness {
+            value: Value::Keyword("auto".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value] if TextDecorationThickness::is_valid(value) => {
+                vec![Property::TextDecorationThickness(TextDecorationThickness {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
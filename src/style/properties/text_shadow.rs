@@ -0,0 +1,44 @@
+use crate::css::types::Value;
+
+use super::{box_shadow::Shadow, CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TextShadow {
+    value: Vec<Shadow>,
+}
+
+impl TextShadow {
+    pub(super) fn new() -> Self {
+        TextShadow { value: Vec::new() }
+    }
+
+    pub(crate) fn actual_value(&self) -> &[Shadow] {
+        &self.value
+    }
+}
+
+impl CssProperty for TextShadow {
+    fn name(&self) -> &'static str {
+        "text-shadow"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::TextShadow(TextShadow { value: Vec::new() })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        let Some(shadows) = Shadow::parse_list(value, false) else {
+            return Vec::new();
+        };
+
+        vec![Property::TextShadow(TextShadow { value: shadows })]
+    }
+}
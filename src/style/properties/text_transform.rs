@@ -0,0 +1,99 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TextTransform {
+    value: Vec<Value>,
+}
+
+impl TextTransform {
+    pub(super) fn new() -> Self {
+        TextTransform { value: Vec::new() }
+    }
+
+    /// Transforms a piece of a word. `at_word_start` tells whether the piece begins the
+    /// word, or continues one whose first letter came before it.
+    pub(crate) fn apply(&self, text: &str, mut at_word_start: bool) -> String {
+        let case = self.value.iter().find_map(|value| match value {
+            Value::Keyword(keyword) if keyword != "full-width" && keyword != "none" => {
+                Some(keyword.as_str())
+            }
+            _ => None,
+        });
+        let mut ans = String::new();
+
+        for c in text.chars() {
+            let is_letter = c.is_alphanumeric();
+
+            match case {
+                Some("uppercase") => ans.extend(c.to_uppercase()),
+                Some("lowercase") => ans.extend(c.to_lowercase()),
+                Some("capitalize") if at_word_start && is_letter => ans.extend(c.to_uppercase()),
+                _ => ans.push(c),
+            }
+
+            if is_letter {
+                at_word_start = false;
+            }
+        }
+
+        if self
+            .value
+            .iter()
+            .any(|value| Validations::keyword(value, &["full-width"]))
+        {
+            ans = ans.chars().map(TextTransform::to_full_width).collect();
+        }
+
+        ans
+    }
+
+    /// Maps printable ASCII characters to their fullwidth forms.
+    fn to_full_width(c: char) -> char {
+        match c {
+            '!'..='~' => char::from_u32(c as u32 - '!' as u32 + 0xFF01).unwrap_or(c),
+            _ => c,
+        }
+    }
+}
+
+impl CssProperty for TextTransform {
+    fn name(&self) -> &'static str {
+        "text-transform"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::TextTransform(TextTransform {
+            value: vec![Value::Keyword("none".to_string())],
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        let is_case =
+            |v: &Value| Validations::keyword(v, &["capitalize", "uppercase", "lowercase"]);
+        let is_full_width = |v: &Value| Validations::keyword(v, &["full-width"]);
+
+        let is_valid = match value {
+            [v] => Validations::keyword(v, &["none"]) || is_case(v) || is_full_width(v),
+            [a, b] => (is_case(a) && is_full_width(b)) || (is_full_width(a) && is_case(b)),
+            _ => false,
+        };
+
+        if !is_valid {
+            return Vec::new();
+        }
+
+        vec![Property::TextTransform(TextTransform {
+            value: value.to_vec(),
+        })]
+    }
+}
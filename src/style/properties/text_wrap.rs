@@ -0,0 +1,62 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TextWrap {
+    value: Value,
+}
+
+impl TextWrap {
+    pub(super) fn new() -> Self {
+        TextWrap {
+            value: Value::default(),
+        }
+    }
+
+    /// Returns whether lines may wrap at the spaces of the text. The `balance`, `stable` and
+    /// `pretty` styles wrap like `wrap`.
+    pub(crate) fn actual_value(&self) -> bool {
+        match &self.value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "wrap" | "balance" | "stable" | "pretty" => true,
+                "nowrap" => false,
+                _ => panic!("Invalid text-wrap value"),
+            },
+            _ => panic!("Invalid text-wrap value"),
+        }
+    }
+
+    pub(super) fn is_valid(value: &Value) -> bool {
+        Validations::keyword(value, &["wrap", "nowrap", "balance", "stable", "pretty"])
+    }
+}
+
+impl CssProperty for TextWrap {
+    fn name(&self) -> &'static str {
+        "text-wrap"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::TextWrap(TextWrap {
+            value: Value::Keyword("wrap".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value] if TextWrap::is_valid(value) => vec![Property::TextWrap(TextWrap {
+                value: value.clone(),
+            })],
+            _ => Vec::new(),
+        }
+    }
+}
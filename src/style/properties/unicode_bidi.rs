@@ -0,0 +1,108 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{direction::TextDirection, CssProperty, Property};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BidiMode {
+    Normal,
+    Embed,
+    Isolate,
+    BidiOverride,
+    IsolateOverride,
+    Plaintext,
+}
+
+impl BidiMode {
+    /// Returns the Unicode control characters that open and close the text of an element in
+    /// the given direction, as if they surrounded it in the bidirectional algorithm.
+    pub(crate) fn controls(
+        &self,
+        direction: TextDirection,
+    ) -> Option<(&'static str, &'static str)> {
+        let rtl = direction == TextDirection::Rtl;
+
+        match self {
+            BidiMode::Normal => None,
+            BidiMode::Embed if rtl => Some(("\u{202b}", "\u{202c}")),
+            BidiMode::Embed => Some(("\u{202a}", "\u{202c}")),
+            BidiMode::Isolate if rtl => Some(("\u{2067}", "\u{2069}")),
+            BidiMode::Isolate => Some(("\u{2066}", "\u{2069}")),
+            BidiMode::BidiOverride if rtl => Some(("\u{202e}", "\u{202c}")),
+            BidiMode::BidiOverride => Some(("\u{202d}", "\u{202c}")),
+            BidiMode::IsolateOverride if rtl => Some(("\u{2067}\u{202e}", "\u{202c}\u{2069}")),
+            BidiMode::IsolateOverride => Some(("\u{2066}\u{202d}", "\u{202c}\u{2069}")),
+            BidiMode::Plaintext => Some(("\u{2068}", "\u{2069}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct UnicodeBidi {
+    value: Value,
+}
+
+impl UnicodeBidi {
+    pub(super) fn new() -> Self {
+        UnicodeBidi {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> BidiMode {
+        match &self.value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "normal" => BidiMode::Normal,
+                "embed" => BidiMode::Embed,
+                "isolate" => BidiMode::Isolate,
+                "bidi-override" => BidiMode::BidiOverride,
+                "isolate-override" => BidiMode::IsolateOverride,
+                "plaintext" => BidiMode::Plaintext,
+                _ => panic!("Invalid unicode-bidi value"),
+            },
+            _ => panic!("Invalid unicode-bidi value"),
+        }
+    }
+}
+
+impl CssProperty for UnicodeBidi {
+    fn name(&self) -> &'static str {
+        "unicode-bidi"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::UnicodeBidi(UnicodeBidi {
+            value: Value::Keyword("normal".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value]
+                if Validations::keyword(
+                    value,
+                    &[
+                        "normal",
+                        "embed",
+                        "isolate",
+                        "bidi-override",
+                        "isolate-override",
+                        "plaintext",
+                    ],
+                ) =>
+            {
+                vec![Property::UnicodeBidi(UnicodeBidi {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
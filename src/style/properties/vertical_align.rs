@@ -0,0 +1,97 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum VerticalAlignMode {
+    Baseline,
+    Sub,
+    Super,
+    TextTop,
+    TextBottom,
+    Middle,
+    Top,
+    Bottom,
+    /// Raised above the baseline of the parent by the given amount, in pixels.
+    Length(f32),
+    /// Raised above the baseline of the parent by a percentage of the line height.
+    Percentage(f32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct VerticalAlign {
+    value: Value,
+}
+
+impl VerticalAlign {
+    pub(super) fn new() -> Self {
+        VerticalAlign {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> VerticalAlignMode {
+        match &self.value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "baseline" => VerticalAlignMode::Baseline,
+                "sub" => VerticalAlignMode::Sub,
+                "super" => VerticalAlignMode::Super,
+                "text-top" => VerticalAlignMode::TextTop,
+                "text-bottom" => VerticalAlignMode::TextBottom,
+                "middle" => VerticalAlignMode::Middle,
+                "top" => VerticalAlignMode::Top,
+                "bottom" => VerticalAlignMode::Bottom,
+                _ => panic!("Invalid vertical-align value"),
+            },
+            Value::Dimension(length, _) => VerticalAlignMode::Length(*length),
+            Value::Percentage(percentage) => VerticalAlignMode::Percentage(*percentage),
+            _ => panic!("Invalid vertical-align value"),
+        }
+    }
+}
+
+impl CssProperty for VerticalAlign {
+    fn name(&self) -> &'static str {
+        "vertical-align"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        false
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::VerticalAlign(VerticalAlign {
+            value: Value::Keyword("baseline".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value]
+                if Validations::keyword(
+                    value,
+                    &[
+                        "baseline",
+                        "sub",
+                        "super",
+                        "text-top",
+                        "text-bottom",
+                        "middle",
+                        "top",
+                        "bottom",
+                    ],
+                ) || Validations::length(value)
+                    || Validations::percentage(value) =>
+            {
+                vec![Property::VerticalAlign(VerticalAlign {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{text_wrap::TextWrap, white_space_collapse::WhiteSpaceCollapse, CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct WhiteSpace;
+
+impl WhiteSpace {
+    pub(crate) fn new() -> Self {
+        WhiteSpace
+    }
+
+    fn longhands(collapse: &str, wrap: &str) -> Vec<Property> {
+        let mut ans = Vec::new();
+
+        ans.extend(WhiteSpaceCollapse::new().maybe_new(&[Value::Keyword(collapse.to_string())]));
+        ans.extend(TextWrap::new().maybe_new(&[Value::Keyword(wrap.to_string())]));
+
+        ans
+    }
+}
+
+impl CssProperty for WhiteSpace {
+    fn name(&self) -> &'static str {
+        "white-space"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        true
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        WhiteSpace::longhands("collapse", "wrap")
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        if let [Value::Keyword(keyword)] = value {
+            let longhands = match keyword.as_str() {
+                "normal" => Some(("collapse", "wrap")),
+                "pre" => Some(("preserve", "nowrap")),
+                "pre-wrap" => Some(("preserve", "wrap")),
+                "pre-line" => Some(("preserve-breaks", "wrap")),
+                "nowrap" => Some(("collapse", "nowrap")),
+                _ => None,
+            };
+
+            if let Some((collapse, wrap)) = longhands {
+                return WhiteSpace::longhands(collapse, wrap);
+            }
+        }
+
+        // Otherwise the longhand values in any order, each at most once.
+        let collapse = value.iter().find(|v| WhiteSpaceCollapse::is_valid(v));
+        let wrap = value
+            .iter()
+            .find(|v| Validations::keyword(v, &["wrap", "nowrap"]));
+
+        if value.is_empty() || collapse.iter().len() + wrap.iter().len() != value.len() {
+            return Vec::new();
+        }
+
+        let mut ans = Vec::new();
+
+        match collapse {
+            Some(collapse) => {
+                ans.extend(WhiteSpaceCollapse::new().maybe_new(std::slice::from_ref(collapse)))
+            }
+            None => ans.extend(WhiteSpaceCollapse::new().initial_value()),
+        }
+
+        match wrap {
+            Some(wrap) => ans.extend(TextWrap::new().maybe_new(std::slice::from_ref(wrap))),
+            None => ans.extend(TextWrap::new().initial_value()),
+        }
+
+        ans
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum CollapseMode {
+    /// Runs of white space become a single space, removed at the start and end of lines.
+    Collapse,
+    /// Spaces and tabs are kept, as are line breaks.
+    Preserve,
+    /// Spaces and tabs collapse, but line breaks are kept.
+    PreserveBreaks,
+    /// Like `Preserve`, but spaces at the end of a line do not hang.
+    BreakSpaces,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct WhiteSpaceCollapse {
+    value: Value,
+}
+
+impl WhiteSpaceCollapse {
+    pub(super) fn new() -> Self {
+        WhiteSpaceCollapse {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> CollapseMode {
+        match &self.value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "collapse" => CollapseMode::Collapse,
+                "preserve" => CollapseMode::Preserve,
+                "preserve-breaks" => CollapseMode::PreserveBreaks,
+                "break-spaces" => CollapseMode::BreakSpaces,
+                _ => panic!("Invalid white-space-collapse value"),
+            },
+            _ => panic!("Invalid white-space-collapse value"),
+        }
+    }
+
+    pub(super) fn is_valid(value: &Value) -> bool {
+        Validations::keyword(
+            value,
+            &["collapse", "preserve", "preserve-breaks", "break-spaces"],
+        )
+    }
+}
+
+impl CssProperty for WhiteSpaceCollapse {
+    fn name(&self) -> &'static str {
+        "white-space-collapse"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::WhiteSpaceCollapse(WhiteSpaceCollapse {
+            value: Value::Keyword("collapse".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value] if WhiteSpaceCollapse::is_valid(value) => {
+                vec![Property::WhiteSpaceCollapse(WhiteSpaceCollapse {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
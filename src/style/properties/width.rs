@@ -18,6 +18,10 @@ impl Width {
         matches!(&self.value, Value::Keyword(k) if k == "auto")
     }
 
+    pub(crate) fn is_percentage(&self) -> bool {
+        matches!(&self.value, Value::Percentage(_))
+    }
+
     pub(crate) fn actual_value(&self, containing_block_width: f32) -> f32 {
         match &self.value {
             Value::Dimension(value, _) => *value,
@@ -53,8 +57,8 @@ impl CssProperty for Width {
 
         let value = value.first().unwrap();
 
-        if Validations::length(value)
-            || Validations::percentage(value)
+        if Validations::non_negative_length(value)
+            || Validations::non_negative_percentage(value)
             || Validations::keyword(value, &["auto"])
         {
             return vec![Property::Width(Width {
@@ -0,0 +1,73 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum WordBreakMode {
+    Normal,
+    BreakAll,
+    KeepAll,
+    BreakWord,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct WordBreak {
+    value: Value,
+}
+
+impl WordBreak {
+    pub(super) fn new() -> Self {
+        WordBreak {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> WordBreakMode {
+        match &self.value {
+            Value::Keyword(keyword) => match keyword.as_str() {
+                "normal" => WordBreakMode::Normal,
+                "break-all" => WordBreakMode::BreakAll,
+                "keep-all" => WordBreakMode::KeepAll,
+                "break-word" => WordBreakMode::BreakWord,
+                _ => panic!("Invalid word-break value"),
+            },
+            _ => panic!("Invalid word-break value"),
+        }
+    }
+}
+
+impl CssProperty for WordBreak {
+    fn name(&self) -> &'static str {
+        "word-break"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::WordBreak(WordBreak {
+            value: Value::Keyword("normal".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value]
+                if Validations::keyword(
+                    value,
+                    &["normal", "break-all", "keep-all", "break-word"],
+                ) =>
+            {
+                vec![Property::WordBreak(WordBreak {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
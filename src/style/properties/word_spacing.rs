@@ -0,0 +1,55 @@
+use crate::{css::types::Value, style::validations::Validations};
+
+use super::{CssProperty, Property};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct WordSpacing {
+    value: Value,
+}
+
+impl WordSpacing {
+    pub(super) fn new() -> Self {
+        WordSpacing {
+            value: Value::default(),
+        }
+    }
+
+    pub(crate) fn actual_value(&self) -> f32 {
+        match &self.value {
+            Value::Keyword(keyword) if keyword == "normal" => 0.,
+            Value::Dimension(spacing, _) => *spacing,
+            _ => panic!("Invalid word-spacing value"),
+        }
+    }
+}
+
+impl CssProperty for WordSpacing {
+    fn name(&self) -> &'static str {
+        "word-spacing"
+    }
+
+    fn is_inheritable(&self) -> bool {
+        true
+    }
+
+    fn is_shorthand(&self) -> bool {
+        false
+    }
+
+    fn initial_value(&self) -> Vec<Property> {
+        vec![Property::WordSpacing(WordSpacing {
+            value: Value::Keyword("normal".to_string()),
+        })]
+    }
+
+    fn maybe_new(&self, value: &[Value]) -> Vec<Property> {
+        match value {
+            [value] if Validations::keyword(value, &["normal"]) || Validations::length(value) => {
+                vec![Property::WordSpacing(WordSpacing {
+                    value: value.clone(),
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{cell::OnceCell, collections::HashMap};
 
 use crate::{
     css::types::{Declaration, Value},
@@ -7,12 +7,32 @@ use crate::{
         formatting_context::FormattingContext,
     },
     style::properties::{
-        background_color::BackgroundColor, border_color::BorderColor, border_style::BorderStyle,
-        border_width::BorderWidth, color::Color, font_size::FontSize, font_weight::FontWeight,
-        height::Height, line_height::LineHeight, margin_bottom::MarginBottom,
-        margin_left::MarginLeft, margin_right::MarginRight, margin_top::MarginTop,
+        background_clip::BackgroundClip, background_color::BackgroundColor,
+        background_image::BackgroundImage, background_origin::BackgroundOrigin,
+        background_position::BackgroundPosition, background_repeat::BackgroundRepeat,
+        background_size::BackgroundSize, border_bottom_color::BorderBottomColor,
+        border_bottom_left_radius::BorderBottomLeftRadius,
+        border_bottom_right_radius::BorderBottomRightRadius,
+        border_bottom_style::BorderBottomStyle, border_bottom_width::BorderBottomWidth,
+        border_left_color::BorderLeftColor, border_left_style::BorderLeftStyle,
+        border_left_width::BorderLeftWidth, border_right_color::BorderRightColor,
+        border_right_style::BorderRightStyle, border_right_width::BorderRightWidth,
+        border_top_color::BorderTopColor, border_top_left_radius::BorderTopLeftRadius,
+        border_top_right_radius::BorderTopRightRadius, border_top_style::BorderTopStyle,
+        border_top_width::BorderTopWidth, box_decoration_break::BoxDecorationBreak,
+        box_shadow::BoxShadow, color::Color, direction::Direction, font_family::FontFamily,
+        font_size::FontSize, font_style::FontStyle, font_weight::FontWeight, height::Height,
+        hyphens::Hyphens, letter_spacing::LetterSpacing, line_height::LineHeight,
+        margin_bottom::MarginBottom, margin_left::MarginLeft, margin_right::MarginRight,
+        margin_top::MarginTop, opacity::Opacity, overflow_wrap::OverflowWrap,
         padding_bottom::PaddingBottom, padding_left::PaddingLeft, padding_right::PaddingRight,
-        padding_top::PaddingTop, text_align::TextAlign, width::Width, Property,
+        padding_top::PaddingTop, tab_size::TabSize, text_align::TextAlign,
+        text_decoration::Decoration, text_decoration_color::TextDecorationColor,
+        text_decoration_line::TextDecorationLine, text_decoration_style::TextDecorationStyle,
+        text_decoration_thickness::TextDecorationThickness, text_shadow::TextShadow,
+        text_transform::TextTransform, text_wrap::TextWrap, unicode_bidi::UnicodeBidi,
+        vertical_align::VerticalAlign, white_space_collapse::WhiteSpaceCollapse, width::Width,
+        word_break::WordBreak, word_spacing::WordSpacing, Property,
     },
     Node, NodeType,
 };
@@ -23,6 +43,11 @@ use super::properties::{display::Display, PropertyRegistry};
 pub(crate) struct StyledNode<'a> {
     pub(crate) node: &'a Node,
     pub(crate) styles: Styles,
+    /// The decorations established by the node and its ancestors, which its text is drawn
+    /// with. They are not inherited like properties, but propagate to in-flow descendants.
+    pub(crate) text_decorations: Vec<Decoration>,
+    /// The min-content and max-content widths of the content, computed once.
+    pub(crate) intrinsic_widths: OnceCell<(f32, f32)>,
     pub(crate) children: Vec<StyledNode<'a>>,
 }
 
@@ -68,30 +93,79 @@ impl StyledNode<'_> {
 
     pub(crate) fn is_inline_level(&self) -> bool {
         self.display().actual_value() == &Value::Keyword("inline".to_string())
+            || self.is_inline_block()
     }
 
-    pub(crate) fn box_type(&self, formatting_context: FormattingContext) -> BoxType {
-        if self.is_inline_level() {
-            BoxType::Inline(Inline { node: self })
+    /// Inline-blocks are laid out as blocks, then placed on lines like replaced elements.
+    pub(crate) fn is_inline_block(&self) -> bool {
+        self.display().actual_value() == &Value::Keyword("inline-block".to_string())
+    }
+
+    pub(crate) fn box_type(&self, formatting_context: FormattingContext) -> BoxType<'_> {
+        if self.is_inline_level() && !self.is_inline_block() {
+            BoxType::Inline(Inline::new(self))
         } else {
             BoxType::Block(Block {
                 node: self,
                 formatting_context,
+                inline_ancestors: Vec::new(),
             })
         }
     }
 
-    pub(crate) fn children_displayed(&self) -> Vec<&StyledNode> {
+    pub(crate) fn children_displayed(&self) -> Vec<&StyledNode<'_>> {
         self.children
             .iter()
             .filter(|child| !child.has_display_none())
             .collect()
     }
 
+    /// Returns the decorations the node establishes, one for each of its lines.
+    pub(super) fn established_text_decorations(&self) -> Vec<Decoration> {
+        let color = self.color().actual_value();
+        let font_size = self.font_size().actual_value();
+
+        self.text_decoration_line()
+            .actual_value()
+            .into_iter()
+            .map(|line| Decoration {
+                line,
+                style: self.text_decoration_style().actual_value(),
+                color: self.text_decoration_color().actual_value(&color),
+                thickness: self.text_decoration_thickness().actual_value(font_size),
+            })
+            .collect()
+    }
+
+    /// Tells whether the node is the root of a block formatting context, which the margins of
+    /// its children do not collapse through: the root element or an inline-block.
+    pub(crate) fn establishes_block_formatting_context(&self) -> bool {
+        self.is_inline_block()
+            || matches!(&self.node.node_type, NodeType::Element(element) if element.tag_name() == "html")
+    }
+
+    /// Tells whether the inline-level node has block-level descendants, which its inline box
+    /// is broken around. The ones of inline-blocks stay inside them.
+    pub(crate) fn has_block_level_descendants(&self) -> bool {
+        if self.is_inline_block() {
+            return false;
+        }
+
+        self.children_displayed().iter().any(|child| {
+            child.is_block_level()
+                || (child.is_inline_level() && child.has_block_level_descendants())
+        })
+    }
+
     pub(crate) fn formatting_context(&self) -> FormattingContext {
         let children = self.children_displayed();
 
-        if children.is_empty() || children.iter().any(|child| child.is_block_level()) {
+        if children.is_empty()
+            || children.iter().any(|child| {
+                child.is_block_level()
+                    || (child.is_inline_level() && child.has_block_level_descendants())
+            })
+        {
             FormattingContext::Block
         } else {
             FormattingContext::Inline
@@ -109,15 +183,56 @@ impl StyledNode<'_> {
     generate_property_getter!(padding_right, PaddingRight);
     generate_property_getter!(padding_bottom, PaddingBottom);
     generate_property_getter!(padding_left, PaddingLeft);
+    generate_property_getter!(font_family, FontFamily);
     generate_property_getter!(font_size, FontSize);
+    generate_property_getter!(font_style, FontStyle);
     generate_property_getter!(line_height, LineHeight);
     generate_property_getter!(background_color, BackgroundColor);
+    generate_property_getter!(background_clip, BackgroundClip);
+    generate_property_getter!(background_image, BackgroundImage);
+    generate_property_getter!(background_origin, BackgroundOrigin);
+    generate_property_getter!(background_position, BackgroundPosition);
+    generate_property_getter!(background_repeat, BackgroundRepeat);
+    generate_property_getter!(background_size, BackgroundSize);
     generate_property_getter!(color, Color);
     generate_property_getter!(font_weight, FontWeight);
     generate_property_getter!(text_align, TextAlign);
-    generate_property_getter!(border_width, BorderWidth);
-    generate_property_getter!(border_color, BorderColor);
-    generate_property_getter!(border_style, BorderStyle);
+    generate_property_getter!(direction, Direction);
+    generate_property_getter!(unicode_bidi, UnicodeBidi);
+    generate_property_getter!(vertical_align, VerticalAlign);
+    generate_property_getter!(border_top_width, BorderTopWidth);
+    generate_property_getter!(border_top_color, BorderTopColor);
+    generate_property_getter!(border_top_style, BorderTopStyle);
+    generate_property_getter!(border_right_width, BorderRightWidth);
+    generate_property_getter!(border_right_color, BorderRightColor);
+    generate_property_getter!(border_right_style, BorderRightStyle);
+    generate_property_getter!(border_bottom_width, BorderBottomWidth);
+    generate_property_getter!(border_bottom_color, BorderBottomColor);
+    generate_property_getter!(border_bottom_style, BorderBottomStyle);
+    generate_property_getter!(border_left_width, BorderLeftWidth);
+    generate_property_getter!(border_left_color, BorderLeftColor);
+    generate_property_getter!(border_left_style, BorderLeftStyle);
+    generate_property_getter!(border_top_left_radius, BorderTopLeftRadius);
+    generate_property_getter!(border_top_right_radius, BorderTopRightRadius);
+    generate_property_getter!(border_bottom_right_radius, BorderBottomRightRadius);
+    generate_property_getter!(border_bottom_left_radius, BorderBottomLeftRadius);
+    generate_property_getter!(box_shadow, BoxShadow);
+    generate_property_getter!(box_decoration_break, BoxDecorationBreak);
+    generate_property_getter!(text_decoration_line, TextDecorationLine);
+    generate_property_getter!(text_decoration_style, TextDecorationStyle);
+    generate_property_getter!(text_decoration_color, TextDecorationColor);
+    generate_property_getter!(text_decoration_thickness, TextDecorationThickness);
+    generate_property_getter!(text_shadow, TextShadow);
+    generate_property_getter!(text_transform, TextTransform);
+    generate_property_getter!(letter_spacing, LetterSpacing);
+    generate_property_getter!(word_spacing, WordSpacing);
+    generate_property_getter!(white_space_collapse, WhiteSpaceCollapse);
+    generate_property_getter!(text_wrap, TextWrap);
+    generate_property_getter!(tab_size, TabSize);
+    generate_property_getter!(overflow_wrap, OverflowWrap);
+    generate_property_getter!(word_break, WordBreak);
+    generate_property_getter!(hyphens, Hyphens);
+    generate_property_getter!(opacity, Opacity);
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -1,5 +1,30 @@
+use font_kit::family_name::FamilyName;
+
 use crate::css::types::Rgb;
 
+pub(crate) fn family_to_family_name(family: &str) -> FamilyName {
+    match family {
+        "serif" => FamilyName::Serif,
+        "sans-serif" | "system-ui" => FamilyName::SansSerif,
+        "monospace" => FamilyName::Monospace,
+        "cursive" => FamilyName::Cursive,
+        _ => FamilyName::Title(family.to_string()),
+    }
+}
+
+/// Converts a `font-family` list into the `FamilyName`s font-kit expects, falling back to
+/// sans-serif when none of the listed families is installed.
+pub(crate) fn families_to_family_names(families: &[String]) -> Vec<FamilyName> {
+    let mut family_names: Vec<FamilyName> = families
+        .iter()
+        .map(|family| family_to_family_name(family))
+        .collect();
+
+    family_names.push(FamilyName::SansSerif);
+
+    family_names
+}
+
 pub(crate) fn keyword_to_rgb(keyword: &str) -> Rgb {
     match keyword {
         "black" => Rgb::new(0, 0, 0, 1.0),
@@ -15,10 +15,30 @@ impl Validations {
         false
     }
 
+    pub(crate) fn non_negative_length(value: &Value) -> bool {
+        matches!(value, Value::Dimension(value, _) if *value >= 0.) && Validations::length(value)
+    }
+
+    pub(crate) fn angle(value: &Value) -> bool {
+        if let Value::Dimension(value, unit) = value {
+            if *value == 0. {
+                return true;
+            }
+
+            return matches!(unit, Unit::Deg);
+        }
+
+        false
+    }
+
     pub(crate) fn percentage(value: &Value) -> bool {
         matches!(value, Value::Percentage(_))
     }
 
+    pub(crate) fn non_negative_percentage(value: &Value) -> bool {
+        matches!(value, Value::Percentage(value) if *value >= 0.)
+    }
+
     pub(crate) fn keyword(value: &Value, keywords: &[&'static str]) -> bool {
         if let Value::Keyword(keyword) = value {
             return keywords.contains(&keyword.as_str());
@@ -38,15 +58,26 @@ impl Validations {
     }
 
     pub(crate) fn padding_width(value: &Value) -> bool {
-        Validations::length(value) || Validations::percentage(value)
+        Validations::non_negative_length(value) || Validations::non_negative_percentage(value)
     }
 
     pub(crate) fn border_style(value: &Value) -> bool {
-        Validations::keyword(value, &["none", "hidden", "solid"])
+        Validations::keyword(
+            value,
+            &[
+                "none", "hidden", "dotted", "dashed", "solid", "double", "groove", "ridge",
+                "inset", "outset",
+            ],
+        )
+    }
+
+    pub(crate) fn box_area(value: &Value) -> bool {
+        Validations::keyword(value, &["border-box", "padding-box", "content-box"])
     }
 
     pub(crate) fn border_width(value: &Value) -> bool {
-        Validations::length(value) || Validations::keyword(value, &["thin", "medium", "thick"])
+        Validations::non_negative_length(value)
+            || Validations::keyword(value, &["thin", "medium", "thick"])
     }
 
     pub(crate) fn numbers(value: &Value, numbers: &[i32]) -> bool {
@@ -64,6 +95,13 @@ impl Validations {
             || Validations::numbers(value, &[100, 200, 300, 400, 500, 600, 700, 800, 900])
     }
 
+    pub(crate) fn generic_family(value: &Value) -> bool {
+        Validations::keyword(
+            value,
+            &["serif", "sans-serif", "monospace", "cursive", "system-ui"],
+        )
+    }
+
     pub(crate) fn number(value: &Value) -> bool {
         matches!(value, Value::Dimension(_, Unit::None))
     }